@@ -1,10 +1,15 @@
 use std::io::{self, Write};
 
-use bulls_and_cows::Host;
+use bulls_and_cows::{
+    parser::{AlphabetCodec, Codec},
+    Host,
+};
 
 const LETTER_LENGTH: usize = 4; // the reasonable range is 1~10
 
 fn main() {
+    let codec = AlphabetCodec::new(bulls_and_cows::Letters::generate_numeric_letters());
+
     let mut host = Host::build_with_random_answer(
         bulls_and_cows::Letters::generate_numeric_letters(),
         LETTER_LENGTH,
@@ -25,39 +30,20 @@ fn main() {
 
             io::stdin().read_line(&mut guess).expect("Failed to read line");
 
-            let guess = guess.trim();
-
-            if guess.len() != LETTER_LENGTH {
-                println!("Wrong format!");
-
-                continue;
-            }
-
-            let mut answer = Vec::with_capacity(LETTER_LENGTH);
+            let answer = match codec.decode(&guess) {
+                Ok(answer) => answer,
+                Err(err) => {
+                    println!("Wrong format! {}", err);
 
-            for i in 0..LETTER_LENGTH {
-                match guess.get(i..(i + 1)) {
-                    Some(o) => match o.parse() {
-                        Ok(n) => answer.push(n),
-                        Err(_) => {
-                            println!("Wrong format!");
-
-                            continue;
-                        },
-                    },
-                    None => {
-                        println!("Wrong format!");
-
-                        continue;
-                    },
-                }
-            }
+                    continue;
+                },
+            };
 
             match host.answer(&answer) {
-                Ok((a, b)) => {
-                    println!("{}A{}B", a, b);
+                Ok(score) => {
+                    println!("{}", score);
 
-                    if a == LETTER_LENGTH {
+                    if score.is_win(LETTER_LENGTH) {
                         println!("Congratulations!");
                         println!();
                         break;
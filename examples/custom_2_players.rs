@@ -4,30 +4,34 @@ use std::{
 };
 
 use bulls_and_cows::{
-    parser::ABParser,
+    parser::{ABParser, AlphabetCodec, Codec, ParseError},
     play::players::{ComputerGuesser, ComputerQuestioner, Guesser, Questioner},
-    Host, HostError,
+    Host, HostError, Score,
 };
 
 const COM_THINKING_DELAY: u64 = 750;
 
-static AB_PARSER: LazyLock<ABParser> = LazyLock::new(ABParser::new);
+static CODEC: LazyLock<AlphabetCodec<u8>> =
+    LazyLock::new(|| AlphabetCodec::new(bulls_and_cows::Letters::generate_numeric_letters()));
 
 pub enum GameError {
     HostError(HostError<u8>),
-    ABIncorrect((usize, usize)),
-    ABError(String),
+    ABError(String, ParseError),
     GuessIncorrect,
 }
 
 pub struct CLIUserQuestioner {
-    letter_length: usize,
+    ab_parser: ABParser,
 }
 
 impl CLIUserQuestioner {
     pub fn new(letter_length: usize) -> CLIUserQuestioner {
+        let mut ab_parser = ABParser::with_all_notations();
+
+        ab_parser.set_answer_length(Some(letter_length));
+
         CLIUserQuestioner {
-            letter_length,
+            ab_parser,
         }
     }
 }
@@ -41,7 +45,7 @@ impl Questioner<u8> for CLIUserQuestioner {
         io::stdin().read_line(&mut String::new()).expect("Failed to read line");
     }
 
-    fn answer(&self, _answer: &[u8]) -> Result<(usize, usize), Self::Error> {
+    fn answer(&self, _answer: &[u8]) -> Result<Score, Self::Error> {
         print!("> ");
 
         let mut reply = String::new();
@@ -50,16 +54,7 @@ impl Questioner<u8> for CLIUserQuestioner {
 
         io::stdin().read_line(&mut reply).expect("Failed to read line");
 
-        match AB_PARSER.parse(&reply) {
-            Some((a, b)) => {
-                if a + b > self.letter_length {
-                    Err(GameError::ABIncorrect((a, b)))
-                } else {
-                    Ok((a, b))
-                }
-            },
-            None => Err(GameError::ABError(reply)),
-        }
+        self.ab_parser.parse(&reply).map_err(|err| GameError::ABError(reply, err))
     }
 }
 
@@ -88,13 +83,11 @@ impl Guesser<u8> for CLIUserGuesser {
         self.guess_times = guess_times
     }
 
-    fn add_condition(&mut self, _guess: &[u8], _reply: (usize, usize)) {
+    fn add_condition(&mut self, _guess: &[u8], _reply: Score) {
         // do nothing
     }
 
     fn guess(&self) -> Result<Vec<u8>, Self::Error> {
-        let mut answer = Vec::with_capacity(self.letter_length);
-
         print!("> ");
 
         let mut guess = String::new();
@@ -103,26 +96,12 @@ impl Guesser<u8> for CLIUserGuesser {
 
         io::stdin().read_line(&mut guess).expect("Failed to read line");
 
-        let guess = guess.trim();
+        let answer = CODEC.decode(&guess).map_err(|_| GameError::GuessIncorrect)?;
 
-        if guess.len() != self.letter_length {
+        if answer.len() != self.letter_length {
             return Err(GameError::GuessIncorrect);
         }
 
-        for i in 0..self.letter_length {
-            match guess.get(i..(i + 1)) {
-                Some(o) => match o.parse() {
-                    Ok(n) => answer.push(n),
-                    Err(_) => {
-                        return Err(GameError::GuessIncorrect);
-                    },
-                },
-                None => {
-                    return Err(GameError::GuessIncorrect);
-                },
-            }
-        }
-
         Ok(answer)
     }
 }
@@ -142,7 +121,7 @@ impl Questioner<u8> for QuestioningPlayer {
         }
     }
 
-    fn answer(&self, answer: &[u8]) -> Result<(usize, usize), Self::Error> {
+    fn answer(&self, answer: &[u8]) -> Result<Score, Self::Error> {
         match self {
             QuestioningPlayer::CLIUser(p) => p.answer(answer),
             QuestioningPlayer::Computer(p) => p.answer(answer).map_err(GameError::HostError),
@@ -175,7 +154,7 @@ impl Guesser<u8> for GuessingPlayer {
         }
     }
 
-    fn add_condition(&mut self, guess: &[u8], reply: (usize, usize)) {
+    fn add_condition(&mut self, guess: &[u8], reply: Score) {
         match self {
             GuessingPlayer::CLIUser(p) => p.add_condition(guess, reply),
             GuessingPlayer::Computer(p) => p.add_condition(guess, reply),
@@ -249,27 +228,23 @@ fn main() {
                 Ok(line) => match line {
                     1 => {
                         qp = QuestioningPlayer::CLIUser(CLIUserQuestioner::new(letter_length));
-                        gp = GuessingPlayer::Computer(ComputerGuesser::new(
-                            &host,
-                            COM_THINKING_DELAY,
-                        ));
+                        gp = GuessingPlayer::Computer(
+                            ComputerGuesser::new(&host, COM_THINKING_DELAY).unwrap(),
+                        );
                     },
                     2 => {
-                        qp = QuestioningPlayer::Computer(ComputerQuestioner::new(
-                            host,
-                            COM_THINKING_DELAY,
-                        ));
+                        qp = QuestioningPlayer::Computer(
+                            ComputerQuestioner::new(host, COM_THINKING_DELAY).unwrap(),
+                        );
                         gp = GuessingPlayer::CLIUser(CLIUserGuesser::new(letter_length));
                     },
                     3 => {
-                        gp = GuessingPlayer::Computer(ComputerGuesser::new(
-                            &host,
-                            COM_THINKING_DELAY,
-                        ));
-                        qp = QuestioningPlayer::Computer(ComputerQuestioner::new(
-                            host,
-                            COM_THINKING_DELAY,
-                        ));
+                        gp = GuessingPlayer::Computer(
+                            ComputerGuesser::new(&host, COM_THINKING_DELAY).unwrap(),
+                        );
+                        qp = QuestioningPlayer::Computer(
+                            ComputerQuestioner::new(host, COM_THINKING_DELAY).unwrap(),
+                        );
                     },
                     _ => continue,
                 },
@@ -290,13 +265,7 @@ fn main() {
                         let guess_times = gp.get_guess_times();
                         gp.set_guess_times(guess_times + 1);
 
-                        let mut s = String::with_capacity(letter_length);
-
-                        for e in guess.iter() {
-                            s.push((e + b'0') as char);
-                        }
-
-                        println!("Guesser: {}", s);
+                        println!("Guesser: {}", CODEC.encode(&guess));
 
                         io::stdout().flush().expect("Failed to flush");
 
@@ -310,11 +279,12 @@ fn main() {
                                             println!("Questioner: Are you kidding?");
                                             continue 'guess;
                                         },
-                                        GameError::ABIncorrect((a, b)) => {
-                                            println!("Questioner: {}A{}B... just kidding.", a, b);
-                                        },
-                                        GameError::ABError(s) => {
-                                            println!("Questioner: {}... just kidding.", s.trim());
+                                        GameError::ABError(s, err) => {
+                                            println!(
+                                                "Questioner: {}... just kidding. ({})",
+                                                s.trim(),
+                                                err
+                                            );
                                         },
                                     }
 
@@ -331,9 +301,9 @@ fn main() {
                 }
             };
 
-            println!("Questioner: {}A{}B", ab.0, ab.1);
+            println!("Questioner: {}", ab);
 
-            if ab.0 == letter_length {
+            if ab.is_win(letter_length) {
                 let times = gp.get_guess_times();
 
                 if times > 1 {
@@ -0,0 +1,230 @@
+/*!
+Commitments of answers, for provably fair hosting.
+
+When a round starts, the host publishes a `Commitment`, which is a salted SHA-256 digest of the answer and of whether the same letter can be used more than once. The constraints of the host are not committed. After the round, the host reveals the salt and the answer as an `Opening`, and anyone can `verify` that the answer matches the commitment and that every recorded reply was consistent with it.
+*/
+
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use sha2::{Digest, Sha256};
+
+use crate::{scorer::Scorer, Alphabet};
+
+/// The length of salts in bytes.
+pub const SALT_LENGTH: usize = 32;
+
+const DOMAIN: &[u8] = b"bulls-and-cows commitment v2";
+
+/// A salted SHA-256 digest of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Commitment {
+    digest: [u8; 32],
+}
+
+impl Commitment {
+    /// Create a commitment from the bytes of a published digest.
+    #[inline]
+    pub const fn from_bytes(digest: [u8; 32]) -> Commitment {
+        Commitment {
+            digest,
+        }
+    }
+
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Commit an answer and the mode with a salt. The letters are committed by their indices in the alphabet, so the commitment does not depend on how the letters are represented. If the answer contains a letter which is not in the alphabet, it returns `None`.
+    pub fn new<T: Eq>(
+        letters: &Alphabet<T>,
+        duplicated_letters_allowed: bool,
+        salt: &[u8; SALT_LENGTH],
+        answer: &[T],
+    ) -> Option<Commitment> {
+        let mut hasher = Sha256::new();
+
+        hasher.update(DOMAIN);
+        hasher.update(salt);
+        hasher.update([u8::from(duplicated_letters_allowed)]);
+        hasher.update((letters.len() as u64).to_le_bytes());
+        hasher.update((answer.len() as u64).to_le_bytes());
+
+        for letter in answer {
+            hasher.update((letters.index_of(letter)? as u64).to_le_bytes());
+        }
+
+        Some(Commitment {
+            digest: hasher.finalize().into()
+        })
+    }
+}
+
+impl Display for Commitment {
+    /// Format the digest in lowercase hexadecimal.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        for b in self.digest.iter() {
+            f.write_fmt(format_args!("{:02x}", b))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The salt and the answer revealed after a round, which open a commitment. If it is made by a host which wipes its answer, the salt and the answer are wiped when it is dropped.
+#[derive(Clone)]
+pub struct Opening<T> {
+    salt:   [u8; SALT_LENGTH],
+    answer: Vec<T>,
+    #[cfg(feature = "zeroize")]
+    wipe:   Option<fn(&mut T)>,
+}
+
+impl<T: Debug> Debug for Opening<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(Opening, f, self, .salt, .answer);
+    }
+}
+
+impl<T: PartialEq> PartialEq for Opening<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.salt == other.salt && self.answer == other.answer
+    }
+}
+
+impl<T: Eq> Eq for Opening<T> {}
+
+impl<T: Hash> Hash for Opening<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.salt.hash(state);
+        self.answer.hash(state);
+    }
+}
+
+impl<T> Opening<T> {
+    /// Create an opening from a revealed salt and answer.
+    #[inline]
+    pub const fn new(salt: [u8; SALT_LENGTH], answer: Vec<T>) -> Opening<T> {
+        Opening {
+            salt,
+            answer,
+            #[cfg(feature = "zeroize")]
+            wipe: None,
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[inline]
+    pub(crate) fn set_wipe(&mut self, wipe: Option<fn(&mut T)>) {
+        self.wipe = wipe;
+    }
+
+    #[inline]
+    pub fn get_salt(&self) -> &[u8; SALT_LENGTH] {
+        &self.salt
+    }
+
+    #[inline]
+    pub fn get_answer(&self) -> &[T] {
+        &self.answer
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> Drop for Opening<T> {
+    #[inline]
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        if let Some(wipe) = self.wipe {
+            self.salt.zeroize();
+            self.answer.iter_mut().for_each(wipe);
+        }
+    }
+}
+
+/// The possible errors for verifying a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommitmentError {
+    /// The revealed salt and answer do not match the commitment.
+    AnswerMismatch,
+    /// The guess at the index of the transcript does not have the length of the answer (or is empty, if the length is hidden), contains a letter which is not in the alphabet, or contains duplicated letters when the mode does not allow them.
+    GuessIncorrect { index: usize },
+    /// The reply at the index of the transcript is not the reply to the guess for the revealed answer.
+    ReplyMismatch { index: usize },
+}
+
+impl Display for CommitmentError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            CommitmentError::AnswerMismatch => {
+                f.write_str("The revealed answer does not match the commitment.")
+            },
+            CommitmentError::GuessIncorrect {
+                index,
+            } => f.write_fmt(format_args!("The guess #{} is incorrect.", index)),
+            CommitmentError::ReplyMismatch {
+                index,
+            } => f.write_fmt(format_args!(
+                "The reply to the guess #{} is inconsistent with the revealed answer.",
+                index
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CommitmentError {}
+
+/// Verify a round. It checks that the opening matches the commitment made with the mode, and replays every guess in the transcript to check that the guess was allowed and that the recorded reply is the one decided by the scorer for the revealed answer.
+pub fn verify<T: Eq, S: Scorer<T>, G: AsRef<[T]>>(
+    letters: &Alphabet<T>,
+    duplicated_letters_allowed: bool,
+    scorer: &S,
+    commitment: &Commitment,
+    opening: &Opening<T>,
+    transcript: &[(G, S::Reply)],
+) -> Result<(), CommitmentError> {
+    let answer = opening.get_answer();
+
+    if Commitment::new(letters, duplicated_letters_allowed, opening.get_salt(), answer).as_ref()
+        != Some(commitment)
+    {
+        return Err(CommitmentError::AnswerMismatch);
+    }
+
+    for (index, (guess, reply)) in transcript.iter().enumerate() {
+        let guess = guess.as_ref();
+
+        // when the length is hidden, guesses of any length are allowed
+        let length_incorrect =
+            if scorer.is_length_hidden() { guess.is_empty() } else { guess.len() != answer.len() };
+
+        let duplicated = !duplicated_letters_allowed
+            && guess.iter().enumerate().any(|(i, letter)| guess[..i].contains(letter));
+
+        if length_incorrect || duplicated || guess.iter().any(|letter| !letters.contains(letter)) {
+            return Err(CommitmentError::GuessIncorrect {
+                index,
+            });
+        }
+
+        if scorer.score(answer, guess) != *reply {
+            return Err(CommitmentError::ReplyMismatch {
+                index,
+            });
+        }
+    }
+
+    Ok(())
+}
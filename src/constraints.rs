@@ -0,0 +1,157 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::ControlFlow,
+};
+
+use crate::Alphabet;
+
+type Predicate<T> = Box<dyn Fn(&[T]) -> bool + Send + Sync>;
+
+/// Rules which answers must follow, such as "no leading zero". The rules are declarative (letters forbidden at positions and letters required somewhere) and a custom predicate.
+pub struct Constraints<T> {
+    forbidden_letters: Vec<(usize, T)>,
+    required_letters:  Vec<T>,
+    predicate:         Option<Predicate<T>>,
+}
+
+impl<T: Debug> Debug for Constraints<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(Constraints, f, self, .forbidden_letters, .required_letters, (.predicate, "{}", if self.predicate.is_some() { "Some(..)" } else { "None" }));
+    }
+}
+
+impl<T> Constraints<T> {
+    /// Create constraints which allow every answer.
+    #[inline]
+    pub const fn new() -> Constraints<T> {
+        Constraints {
+            forbidden_letters: Vec::new(),
+            required_letters:  Vec::new(),
+            predicate:         None,
+        }
+    }
+
+    /// Whether these constraints allow every answer.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.forbidden_letters.is_empty()
+            && self.required_letters.is_empty()
+            && self.predicate.is_none()
+    }
+
+    /// Forbid the letter at the position (counted from `0`).
+    #[inline]
+    pub fn forbid(&mut self, position: usize, letter: T) {
+        self.forbidden_letters.push((position, letter));
+    }
+
+    /// Require the letter to be at any position.
+    #[inline]
+    pub fn require(&mut self, letter: T) {
+        self.required_letters.push(letter);
+    }
+
+    /// Set a custom rule. Answers are allowed only if the predicate returns `true` for them, for example, if they are real words. If random answers are rarely allowed, the host has to check every possible answer to pick one, which takes time exponential in the length of the answer, so a rare predicate is only practical for short answers (a list of allowed answers is better drawn by `source::WeightedList`).
+    #[inline]
+    pub fn set_predicate<F: Fn(&[T]) -> bool + Send + Sync + 'static>(&mut self, predicate: F) {
+        self.predicate = Some(Box::new(predicate));
+    }
+}
+
+impl<T: Eq> Constraints<T> {
+    /// Whether the answer follows all of the rules.
+    pub fn is_satisfied(&self, answer: &[T]) -> bool {
+        self.forbidden_letters
+            .iter()
+            .all(|(position, letter)| answer.get(*position).map(|e| e.ne(letter)).unwrap_or(true))
+            && self.required_letters.iter().all(|letter| answer.contains(letter))
+            && self.predicate.as_ref().map(|predicate| predicate(answer)).unwrap_or(true)
+    }
+}
+
+impl<T: Eq> Constraints<T> {
+    /// Whether the declarative rules can never be followed by an answer with the length, so that there is no need to look for one. The predicate is not taken into account.
+    pub(crate) fn is_unsatisfiable(
+        &self,
+        letters: &Alphabet<T>,
+        length: usize,
+        duplicated_letters_allowed: bool,
+    ) -> bool {
+        if self.required_letters.iter().any(|letter| !letters.contains(letter)) {
+            return true;
+        }
+
+        if !duplicated_letters_allowed {
+            let distinct_required_letters = self
+                .required_letters
+                .iter()
+                .enumerate()
+                .filter(|&(i, letter)| !self.required_letters[..i].contains(letter))
+                .count();
+
+            if distinct_required_letters > length {
+                return true;
+            }
+        }
+
+        // a position where every letter is forbidden
+        self.forbidden_letters.iter().any(|&(position, _)| {
+            position < length
+                && letters.iter().all(|letter| {
+                    self.forbidden_letters.iter().any(|(p, l)| *p == position && l == letter)
+                })
+        })
+    }
+}
+
+impl<T> Default for Constraints<T> {
+    #[inline]
+    fn default() -> Self {
+        Constraints::new()
+    }
+}
+
+/// Visit every code made of `length` letters in the lexicographic order of the indices of the letters, until `visit` breaks.
+pub(crate) fn for_each_code(
+    letters_len: usize,
+    length: usize,
+    duplicated_letters_allowed: bool,
+    mut visit: impl FnMut(&[usize]) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    fn visit_from(
+        code: &mut Vec<usize>,
+        used: &mut [bool],
+        length: usize,
+        duplicated_letters_allowed: bool,
+        visit: &mut dyn FnMut(&[usize]) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        if code.len() == length {
+            return visit(code);
+        }
+
+        for i in 0..used.len() {
+            if !duplicated_letters_allowed && used[i] {
+                continue;
+            }
+
+            used[i] = true;
+            code.push(i);
+
+            let result = visit_from(code, used, length, duplicated_letters_allowed, visit);
+
+            code.pop();
+            used[i] = false;
+
+            result?;
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    let mut code = Vec::with_capacity(length);
+    let mut used = vec![false; letters_len];
+
+    visit_from(&mut code, &mut used, length, duplicated_letters_allowed, &mut visit)
+}
@@ -0,0 +1,188 @@
+use core::{
+    array,
+    fmt::{self, Debug, Formatter},
+    hash::Hash,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{host, scorer, Alphabet, Feedback, Host, HostError, Revealed, Score};
+
+/// A game host whose answer has exactly `N` letters. Answering for the question never allocates on the heap, and the replies are exactly the same as the replies of `Host` with the classic rule. Its `Debug` hides the answer (see `reveal`).
+pub struct FixedHost<T: Eq + Hash + Clone, const N: usize> {
+    letters:                    Alphabet<T>,
+    answer:                     [T; N],
+    duplicated_letters_allowed: bool,
+    #[cfg(feature = "zeroize")]
+    wipe:                       Option<fn(&mut T)>,
+}
+
+impl<T: Debug + Eq + Hash + Clone, const N: usize> Debug for FixedHost<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(FixedHost, f, self, .letters, (.answer, "<hidden>"), .duplicated_letters_allowed);
+    }
+}
+
+impl<T: Debug + Eq + Hash + Clone, const N: usize> Debug for Revealed<'_, FixedHost<T, N>> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let host = self.0;
+
+        debug_helper::impl_debug_for_struct!(FixedHost, f, host, .letters, .answer, .duplicated_letters_allowed);
+    }
+}
+
+impl<T: Eq + Hash + Clone, const N: usize> FixedHost<T, N> {
+    pub fn get_letters(&self) -> &Alphabet<T> {
+        &self.letters
+    }
+
+    pub fn get_answer(&self) -> &[T; N] {
+        &self.answer
+    }
+
+    /// Show the answer in the `Debug` output.
+    #[inline]
+    pub fn reveal(&self) -> Revealed<'_, FixedHost<T, N>> {
+        Revealed(self)
+    }
+
+    /// Whether the answer and the guesses can contain the same letter more than once (like Mastermind).
+    pub fn is_duplicated_letters_allowed(&self) -> bool {
+        self.duplicated_letters_allowed
+    }
+
+    /// Allow or disallow the answer and the guesses to contain the same letter more than once. If it is going to be disallowed but the current answer contains duplicated letters, it returns `HostError::AnswerContainsDuplicatedLetter`.
+    pub fn set_duplicated_letters_allowed(&mut self, allowed: bool) -> Result<(), HostError<T>> {
+        if !allowed {
+            host::check_code(&self.letters, false, N..=N, &self.answer)?;
+        }
+
+        self.duplicated_letters_allowed = allowed;
+
+        Ok(())
+    }
+
+    /// Whether the answer is wiped from memory when it is renewed and when this host is dropped.
+    #[cfg(feature = "zeroize")]
+    pub fn is_zeroize(&self) -> bool {
+        self.wipe.is_some()
+    }
+
+    /// Enable or disable wiping the answer from memory when it is renewed and when this host is dropped.
+    #[cfg(feature = "zeroize")]
+    pub fn set_zeroize(&mut self, zeroize: bool)
+    where
+        T: Zeroize, {
+        self.wipe = if zeroize { Some(Zeroize::zeroize) } else { None };
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: Eq + Hash + Clone, const N: usize> Drop for FixedHost<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(wipe) = self.wipe {
+            self.answer.iter_mut().for_each(wipe);
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone, const N: usize> FixedHost<T, N> {
+    /// Build a fixed-length game host with a known answer.
+    pub fn build_with_known_answer(
+        letters: Alphabet<T>,
+        answer: [T; N],
+    ) -> Result<FixedHost<T, N>, HostError<T>> {
+        if letters.is_empty() {
+            Err(HostError::LettersEmpty)
+        } else {
+            let host = FixedHost {
+                letters,
+                answer,
+                duplicated_letters_allowed: false,
+                #[cfg(feature = "zeroize")]
+                wipe: None,
+            };
+
+            host.check_known_answer(&host.answer)?;
+
+            Ok(host)
+        }
+    }
+
+    /// Build a fixed-length game host from the letters, the answer and the mode of a `Host` with the classic rule. If the `Host` wipes its answer, so does the new host. If the length of the answer of the `Host` is not `N`, it returns `HostError::AnswerLengthIncorrect`.
+    pub fn from_host(host: &Host<T>) -> Result<FixedHost<T, N>, HostError<T>> {
+        let answer = host.get_answer();
+
+        if answer.len() != N {
+            return Err(HostError::AnswerLengthIncorrect {
+                expected: N..=N,
+                actual:   answer.len(),
+            });
+        }
+
+        Ok(FixedHost {
+            letters:                          host.get_letters().clone(),
+            answer:                           array::from_fn(|i| answer[i].clone()),
+            duplicated_letters_allowed:       host.is_duplicated_letters_allowed(),
+            #[cfg(feature = "zeroize")]
+            wipe:                             host.get_wipe(),
+        })
+    }
+
+    /// Renew this host with a known answer.
+    pub fn renew_with_known_answer(&mut self, answer: [T; N]) -> Result<(), HostError<T>> {
+        self.check_known_answer(&answer)?;
+
+        #[cfg(feature = "zeroize")]
+        if let Some(wipe) = self.wipe {
+            self.answer.iter_mut().for_each(wipe);
+        }
+
+        self.answer = answer;
+
+        Ok(())
+    }
+
+    fn check_known_answer(&self, answer: &[T; N]) -> Result<(), HostError<T>> {
+        let expected = if self.duplicated_letters_allowed {
+            1..=host::MAX_ANSWER_LENGTH
+        } else {
+            1..=self.letters.len()
+        };
+
+        host::check_code(&self.letters, self.duplicated_letters_allowed, expected, answer)
+    }
+}
+
+impl<T: Eq + Hash + Clone, const N: usize> FixedHost<T, N> {
+    /// Answer for the question. If the format of the input answer is correct, it returns the number of bulls and the number of cows.
+    pub fn answer(&self, answer: &[T; N]) -> Result<Score, HostError<T>> {
+        Ok(Score::from_feedback(&self.answer_detailed(answer)?))
+    }
+
+    /// Whether the reply means the guess is the answer.
+    #[inline]
+    pub fn is_win(&self, reply: &Score) -> bool {
+        reply.is_win(N)
+    }
+
+    /// Answer for the question in detail. If the format of the input answer is correct, it returns the classification of the letter at every position of the input answer. When a letter appears more times in the input answer than in the answer, the leftmost occurrences are the cows.
+    pub fn answer_detailed(&self, answer: &[T; N]) -> Result<[Feedback; N], HostError<T>> {
+        self.check_answer(answer)?;
+
+        let mut feedback = [Feedback::Miss; N];
+        let mut used = [false; N];
+
+        scorer::fill_feedback(&self.answer, answer, &mut feedback, &mut used);
+
+        Ok(feedback)
+    }
+
+    fn check_answer(&self, answer: &[T; N]) -> Result<(), HostError<T>> {
+        host::check_code(&self.letters, self.duplicated_letters_allowed, N..=N, answer)
+    }
+}
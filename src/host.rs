@@ -1,29 +1,61 @@
-use std::{
-    collections::HashSet,
-    error::Error,
+use alloc::{boxed::Box, vec::Vec};
+use core::{
     fmt::{self, Debug, Display, Formatter},
-    hash::Hash,
+    hash::{Hash, Hasher},
+    mem,
+    ops::{ControlFlow, RangeInclusive},
 };
+#[cfg(feature = "std")]
+use std::error::Error;
 
-use rand::seq::SliceRandom;
+use rand::{
+    seq::{IndexedRandom, SliceRandom},
+    Rng, SeedableRng,
+};
+use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+#[cfg(feature = "commitment")]
+use crate::commitment::{Commitment, Opening, SALT_LENGTH};
+use crate::{
+    constraints::{self, Constraints},
+    scorer::{self, BullsAndCows, HiddenLength, PackedCode, Scorer},
+    source::SecretSource,
+    Alphabet, Feedback, Revealed,
+};
 
 #[derive(PartialEq, Eq)]
 /// The possible errors for the `Host` struct.
 pub enum HostError<T: Eq + Hash + Clone> {
     /// The length of letters for a Bulls and Cows game must be at least 1.
     LettersEmpty,
-    /// The length of the answer is incorrect.
-    AnswerLengthIncorrect,
-    /// There is an incorrect letter in the answer.
-    AnswerContainsIncorrectLetter(T),
-    /// There is an duplicated letter in the answer.
-    AnswerContainsDuplicatedLetter(T),
+    /// There is an duplicated letter in the letters.
+    LettersContainsDuplicatedLetter(T),
+    /// The length of the answer is incorrect. The length is expected to be in the range of `expected`.
+    AnswerLengthIncorrect { expected: RangeInclusive<usize>, actual: usize },
+    /// There is an incorrect letter at the index of the answer.
+    AnswerContainsIncorrectLetter { index: usize, letter: T },
+    /// The letter at the index of the answer is a duplicate of the letter at `first_index`.
+    AnswerContainsDuplicatedLetter { index: usize, first_index: usize, letter: T },
+    /// The answer breaks the constraints.
+    AnswerBreaksConstraints,
+    /// No answer with the length follows the constraints.
+    ConstraintsUnsatisfiable,
+    /// The secret source has no answer which the host accepts.
+    SourceEmpty,
+    /// There are no hosts, or the hosts do not have the same letters, mode and lengths of guesses.
+    HostsIncompatible,
+    /// There is no source of randomness. Without the `std` feature, a seed (or a random number generator) has to be given.
+    RngUnseeded,
+    /// All of the answers have been solved, so there is nothing to guess.
+    AnswersSolved,
 }
 
 impl<T: Eq + Hash + Clone> Debug for HostError<T> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        debug_helper::impl_debug_for_enum!(HostError::{LettersEmpty, AnswerLengthIncorrect, (AnswerContainsIncorrectLetter(_): (let .0 = "AnswerContainsIncorrectLetter")), (AnswerContainsDuplicatedLetter(_): (let .0 = "AnswerContainsDuplicatedLetter"))}, f, self);
+        debug_helper::impl_debug_for_enum!(HostError::{LettersEmpty, (LettersContainsDuplicatedLetter(_): (let .0 = "LettersContainsDuplicatedLetter")), {AnswerLengthIncorrect{expected, actual}: (.expected, .actual)}, {AnswerContainsIncorrectLetter{index, letter: _}: (.index)}, {AnswerContainsDuplicatedLetter{index, first_index, letter: _}: (.index, .first_index)}, AnswerBreaksConstraints, ConstraintsUnsatisfiable, SourceEmpty, HostsIncompatible, RngUnseeded, AnswersSolved}, f, self);
     }
 }
 
@@ -34,36 +66,173 @@ impl<T: Eq + Hash + Clone> Display for HostError<T> {
             HostError::LettersEmpty => {
                 f.write_str("The length of letters for a Bulls and Cows game must be at least 1.")
             },
-            HostError::AnswerLengthIncorrect => {
-                f.write_str("The length of the answer is incorrect.")
+            HostError::LettersContainsDuplicatedLetter(_) => {
+                f.write_str("There is an duplicated letter in the letters.")
+            },
+            HostError::AnswerLengthIncorrect {
+                expected,
+                actual,
+            } => {
+                let (start, end) = (*expected.start(), *expected.end());
+
+                if start == end {
+                    f.write_fmt(format_args!(
+                        "The length of the answer must be {}, but it is {}.",
+                        start, actual
+                    ))
+                } else {
+                    f.write_fmt(format_args!(
+                        "The length of the answer must be between {} and {}, but it is {}.",
+                        start, end, actual
+                    ))
+                }
+            },
+            HostError::AnswerContainsIncorrectLetter {
+                index, ..
+            } => f.write_fmt(format_args!(
+                "There is an incorrect letter at index {} of the answer.",
+                index
+            )),
+            HostError::AnswerContainsDuplicatedLetter {
+                index,
+                first_index,
+                ..
+            } => f.write_fmt(format_args!(
+                "The letter at index {} of the answer is a duplicate of the letter at index {}.",
+                index, first_index
+            )),
+            HostError::AnswerBreaksConstraints => f.write_str("The answer breaks the constraints."),
+            HostError::ConstraintsUnsatisfiable => {
+                f.write_str("No answer with the length follows the constraints.")
             },
-            HostError::AnswerContainsIncorrectLetter(_) => {
-                f.write_str("There is an incorrect letter in the answer.")
+            HostError::SourceEmpty => {
+                f.write_str("The secret source has no answer which the host accepts.")
             },
-            HostError::AnswerContainsDuplicatedLetter(_) => {
-                f.write_str("There is an duplicated letter in the answer.")
+            HostError::HostsIncompatible => f.write_str(
+                "There must be hosts with the same letters, mode and lengths of guesses.",
+            ),
+            HostError::RngUnseeded => f.write_str(
+                "There is no source of randomness, so a seed or a random number generator is \
+                 needed.",
+            ),
+            HostError::AnswersSolved => {
+                f.write_str("All of the answers have been solved, so there is nothing to guess.")
             },
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl<T: Eq + Hash + Clone> Error for HostError<T> {}
 
-/// The game host for generating the question and answering for the question.
-pub struct Host<T: Eq + Hash + Clone> {
-    letters: HashSet<T>,
-    answer:  Vec<T>,
+/// The maximum length of an answer when the same letter can be used more than once.
+pub const MAX_ANSWER_LENGTH: usize = 256;
+
+/// How many random answers are drawn before enumerating the answers which follow the constraints.
+const REJECTION_SAMPLING_ATTEMPTS: usize = 64;
+
+/// An answer which has been checked by `Host::validate_answer`. Its `Debug` hides the answer (see `reveal`). If the host wipes its answer, the validated answer is wiped when it is dropped.
+#[derive(Clone)]
+pub struct ValidatedAnswer<T> {
+    answer: Vec<T>,
+    #[cfg(feature = "zeroize")]
+    wipe:   Option<fn(&mut T)>,
 }
 
-impl<T: Debug + Eq + Hash + Clone> Debug for Host<T> {
+impl<T: PartialEq> PartialEq for ValidatedAnswer<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.answer == other.answer
+    }
+}
+
+impl<T: Eq> Eq for ValidatedAnswer<T> {}
+
+impl<T: Hash> Hash for ValidatedAnswer<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.answer.hash(state)
+    }
+}
+
+impl<T> Debug for ValidatedAnswer<T> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        debug_helper::impl_debug_for_struct!(Host, f, self, .letters, .answer);
+        debug_helper::impl_debug_for_struct!(ValidatedAnswer, f, self, (.answer, "<hidden>"));
     }
 }
 
-impl<T: Eq + Hash + Clone> Host<T> {
-    pub fn get_letters(&self) -> &HashSet<T> {
+impl<T: Debug> Debug for Revealed<'_, ValidatedAnswer<T>> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let answer = self.0;
+
+        debug_helper::impl_debug_for_struct!(ValidatedAnswer, f, answer, .answer);
+    }
+}
+
+impl<T> ValidatedAnswer<T> {
+    /// Show the answer in the `Debug` output.
+    #[inline]
+    pub fn reveal(&self) -> Revealed<'_, ValidatedAnswer<T>> {
+        Revealed(self)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.answer
+    }
+
+    /// Take the answer out. It is not wiped any more.
+    #[inline]
+    pub fn into_inner(mut self) -> Vec<T> {
+        mem::take(&mut self.answer)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> Drop for ValidatedAnswer<T> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(wipe) = self.wipe {
+            self.answer.iter_mut().for_each(wipe);
+        }
+    }
+}
+
+/// The game host for generating the question and answering for the question. The replies are decided by the scorer `S`, which is the classic bulls-and-cows rule by default. Its `Debug` hides the answer (see `reveal`).
+pub struct Host<T: Eq + Hash + Clone, S: Scorer<T> = BullsAndCows> {
+    letters:                    Alphabet<T>,
+    answer:                     Vec<T>,
+    packed_answer:              Option<PackedCode>,
+    duplicated_letters_allowed: bool,
+    rng:                        Option<Box<ChaCha8Rng>>,
+    scorer:                     S,
+    constraints:                Constraints<T>,
+    #[cfg(feature = "zeroize")]
+    wipe:                       Option<fn(&mut T)>,
+    #[cfg(feature = "commitment")]
+    salt:                       Option<[u8; SALT_LENGTH]>,
+}
+
+impl<T: Debug + Eq + Hash + Clone, S: Scorer<T> + Debug> Debug for Host<T, S> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(Host, f, self, .letters, (.answer, "<hidden>"), .duplicated_letters_allowed, .scorer, .constraints);
+    }
+}
+
+impl<T: Debug + Eq + Hash + Clone, S: Scorer<T> + Debug> Debug for Revealed<'_, Host<T, S>> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let host = self.0;
+
+        debug_helper::impl_debug_for_struct!(Host, f, host, .letters, .answer, .duplicated_letters_allowed, .scorer, .constraints);
+    }
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> Host<T, S> {
+    pub fn get_letters(&self) -> &Alphabet<T> {
         &self.letters
     }
 
@@ -74,42 +243,169 @@ impl<T: Eq + Hash + Clone> Host<T> {
     pub fn get_answer(&self) -> &[T] {
         &self.answer
     }
+
+    pub fn get_scorer(&self) -> &S {
+        &self.scorer
+    }
+
+    /// Show the answer in the `Debug` output.
+    #[inline]
+    pub fn reveal(&self) -> Revealed<'_, Host<T, S>> {
+        Revealed(self)
+    }
+
+    /// Change the rule for replying to guesses.
+    pub fn with_scorer<S2: Scorer<T>>(mut self, scorer: S2) -> Host<T, S2> {
+        // fields are taken instead of moved, because this host may wipe its answer when it is dropped
+        Host {
+            letters: mem::take(&mut self.letters),
+            answer: mem::take(&mut self.answer),
+            packed_answer: self.packed_answer.take(),
+            duplicated_letters_allowed: self.duplicated_letters_allowed,
+            rng: self.rng.take(),
+            scorer,
+            constraints: mem::take(&mut self.constraints),
+            #[cfg(feature = "zeroize")]
+            wipe: self.wipe,
+            #[cfg(feature = "commitment")]
+            salt: self.salt.take(),
+        }
+    }
+
+    /// Hide the length of the answer from the guessers with the `HiddenLength` variant of the current scorer. Then guesses of any length are allowed.
+    #[inline]
+    pub fn into_hidden_length(self) -> Host<T, HiddenLength<S>>
+    where
+        S: Clone, {
+        let scorer = HiddenLength::new(self.scorer.clone());
+
+        self.with_scorer(scorer)
+    }
+
+    pub fn get_constraints(&self) -> &Constraints<T> {
+        &self.constraints
+    }
+
+    /// Set the rules which answers must follow. If the current answer breaks them, it returns `HostError::AnswerBreaksConstraints` and nothing changes.
+    pub fn set_constraints(&mut self, constraints: Constraints<T>) -> Result<(), HostError<T>> {
+        if !constraints.is_satisfied(&self.answer) {
+            return Err(HostError::AnswerBreaksConstraints);
+        }
+
+        self.constraints = constraints;
+
+        Ok(())
+    }
+
+    /// Whether the answer and the guesses can contain the same letter more than once (like Mastermind).
+    pub fn is_duplicated_letters_allowed(&self) -> bool {
+        self.duplicated_letters_allowed
+    }
+
+    /// Allow or disallow the answer and the guesses to contain the same letter more than once. If it is going to be disallowed but the current answer contains duplicated letters, it returns `HostError::AnswerContainsDuplicatedLetter`.
+    pub fn set_duplicated_letters_allowed(&mut self, allowed: bool) -> Result<(), HostError<T>> {
+        if !allowed {
+            check_code(&self.letters, false, 1..=MAX_ANSWER_LENGTH, &self.answer)?;
+        }
+
+        self.duplicated_letters_allowed = allowed;
+
+        Ok(())
+    }
+
+    /// Whether the answer is wiped from memory when it is renewed and when this host is dropped.
+    #[cfg(feature = "zeroize")]
+    pub fn is_zeroize(&self) -> bool {
+        self.wipe.is_some()
+    }
+
+    /// The function which wipes a letter of the answer, if wiping is enabled.
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn get_wipe(&self) -> Option<fn(&mut T)> {
+        self.wipe
+    }
+
+    /// Enable or disable wiping the answer from memory when it is renewed and when this host is dropped. The answers validated by `validate_answer` and the openings made by `open_commitment` after that are wiped as well.
+    #[cfg(feature = "zeroize")]
+    pub fn set_zeroize(&mut self, zeroize: bool)
+    where
+        T: Zeroize, {
+        self.wipe = if zeroize { Some(Zeroize::zeroize) } else { None };
+    }
+
+    /// Seed the random number generator used by `renew_with_random_answer`. After seeding, the same seed always produces the same sequence of answers from the same alphabet, on every platform.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = Some(Box::new(ChaCha8Rng::seed_from_u64(seed)));
+    }
+
+    /// Whether the host has been seeded (see `set_seed`).
+    pub fn is_seeded(&self) -> bool {
+        self.rng.is_some()
+    }
 }
 
 impl<T: Eq + Hash + Clone> Host<T> {
     /// Build a bulls-and-cows game host with a fixed answer.
-    pub fn build(letters: HashSet<T>, answer_length: usize) -> Result<Host<T>, HostError<T>> {
+    pub fn build(letters: Alphabet<T>, answer_length: usize) -> Result<Host<T>, HostError<T>> {
         if letters.is_empty() {
             Err(HostError::LettersEmpty)
         } else {
             let letters_len = letters.len();
 
             if answer_length == 0 || answer_length > letters_len {
-                Err(HostError::AnswerLengthIncorrect)
+                Err(HostError::AnswerLengthIncorrect {
+                    expected: 1..=letters_len,
+                    actual:   answer_length,
+                })
             } else {
                 let answer: Vec<T> = letters.iter().take(answer_length).cloned().collect();
 
-                Ok(Host {
-                    letters,
-                    answer,
-                })
+                let mut host = Host::empty(letters, Constraints::new());
+
+                host.set_answer(answer);
+
+                Ok(host)
             }
         }
     }
 
     /// Build a bulls-and-cows game host with a random answer.
     pub fn build_with_random_answer(
-        letters: HashSet<T>,
+        letters: Alphabet<T>,
         answer_length: usize,
+    ) -> Result<Host<T>, HostError<T>> {
+        Host::build_with_random_answer_constraints(letters, answer_length, Constraints::new())
+    }
+
+    /// Build a bulls-and-cows game host with a random answer generated by the given random number generator.
+    pub fn build_with_random_answer_rng<R: Rng + ?Sized>(
+        letters: Alphabet<T>,
+        answer_length: usize,
+        rng: &mut R,
+    ) -> Result<Host<T>, HostError<T>> {
+        if letters.is_empty() {
+            Err(HostError::LettersEmpty)
+        } else {
+            let mut host = Host::empty(letters, Constraints::new());
+
+            host.renew_with_random_answer_rng(answer_length, rng)?;
+
+            Ok(host)
+        }
+    }
+
+    /// Build a bulls-and-cows game host with a random answer. The host is seeded with `seed` (see `set_seed`), so this answer and the answers of the following `renew_with_random_answer` calls are reproducible.
+    pub fn build_with_random_answer_seed(
+        letters: Alphabet<T>,
+        answer_length: usize,
+        seed: u64,
     ) -> Result<Host<T>, HostError<T>> {
         if letters.is_empty() {
             Err(HostError::LettersEmpty)
         } else {
-            let mut host = Host {
-                letters,
-                answer: Vec::new(),
-            };
+            let mut host = Host::empty(letters, Constraints::new());
 
+            host.set_seed(seed);
             host.renew_with_random_answer(answer_length)?;
 
             Ok(host)
@@ -118,16 +414,39 @@ impl<T: Eq + Hash + Clone> Host<T> {
 
     /// Build a bulls-and-cows game host with a known answer.
     pub fn build_with_known_answer(
-        letters: HashSet<T>,
+        letters: Alphabet<T>,
+        answer: Vec<T>,
+    ) -> Result<Host<T>, HostError<T>> {
+        Host::build_with_known_answer_constraints(letters, answer, Constraints::new())
+    }
+
+    /// Build a bulls-and-cows game host with a random answer which follows the constraints. Every allowed answer is drawn with the same probability.
+    pub fn build_with_random_answer_constraints(
+        letters: Alphabet<T>,
+        answer_length: usize,
+        constraints: Constraints<T>,
+    ) -> Result<Host<T>, HostError<T>> {
+        if letters.is_empty() {
+            Err(HostError::LettersEmpty)
+        } else {
+            let mut host = Host::empty(letters, constraints);
+
+            host.renew_with_random_answer(answer_length)?;
+
+            Ok(host)
+        }
+    }
+
+    /// Build a bulls-and-cows game host with a known answer which follows the constraints.
+    pub fn build_with_known_answer_constraints(
+        letters: Alphabet<T>,
         answer: Vec<T>,
+        constraints: Constraints<T>,
     ) -> Result<Host<T>, HostError<T>> {
         if letters.is_empty() {
             Err(HostError::LettersEmpty)
         } else {
-            let mut host = Host {
-                letters,
-                answer: Vec::new(),
-            };
+            let mut host = Host::empty(letters, constraints);
 
             host.renew_with_known_answer(answer)?;
 
@@ -135,116 +454,561 @@ impl<T: Eq + Hash + Clone> Host<T> {
         }
     }
 
-    #[allow(clippy::missing_safety_doc)]
-    /// Build a bulls-and-cows game host with a known answer unsafely.
-    pub unsafe fn build_with_known_answer_unsafe(letters: HashSet<T>, answer: Vec<T>) -> Host<T> {
+    /// Build a bulls-and-cows game host with a known answer which is trusted to be valid, without checking it. An invalid answer makes the replies meaningless (but is never undefined behavior), and it is caught by a debug assertion.
+    pub fn from_trusted(letters: Alphabet<T>, answer: Vec<T>) -> Host<T> {
+        let mut host = Host::empty(letters, Constraints::new());
+
+        host.set_answer(answer);
+
+        debug_assert!(
+            !host.letters.is_empty() && host.check_known_answer(&host.answer).is_ok(),
+            "the trusted answer is invalid"
+        );
+
+        host
+    }
+
+    /// Create a host without an answer, which every builder starts from.
+    fn empty(letters: Alphabet<T>, constraints: Constraints<T>) -> Host<T> {
         Host {
             letters,
-            answer,
+            answer: Vec::new(),
+            packed_answer: None,
+            duplicated_letters_allowed: false,
+            rng: None,
+            scorer: BullsAndCows,
+            constraints,
+            #[cfg(feature = "zeroize")]
+            wipe: None,
+            #[cfg(feature = "commitment")]
+            salt: None,
         }
     }
 
-    /// Renew this host with a random answer.
+    #[allow(clippy::missing_safety_doc)]
+    /// Build a bulls-and-cows game host with a known answer unsafely.
+    #[deprecated(note = "nothing unsafe happens here; use `Host::from_trusted` instead")]
+    pub unsafe fn build_with_known_answer_unsafe(letters: Alphabet<T>, answer: Vec<T>) -> Host<T> {
+        Host::from_trusted(letters, answer)
+    }
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> Host<T, S> {
+    /// Renew this host with a random answer. If the host has been seeded (see `set_seed`), the seeded random number generator is used. Without the `std` feature there is no source of entropy, so an unseeded host returns `HostError::RngUnseeded`.
     pub fn renew_with_random_answer(&mut self, answer_length: usize) -> Result<(), HostError<T>> {
-        let letters = &self.letters;
+        let answer = self.draw_random_answer(answer_length)?;
 
-        let letters_len = letters.len();
+        self.set_answer(answer);
 
-        if answer_length == 0 || answer_length > letters_len {
-            Err(HostError::AnswerLengthIncorrect)
-        } else {
-            let mut answer: Vec<T> = Vec::with_capacity(answer_length);
+        Ok(())
+    }
 
-            {
-                let letters_vec: Vec<&T> = letters.iter().collect();
+    /// Draw an answer like `renew_with_random_answer`, but without renewing this host.
+    pub(crate) fn draw_random_answer(
+        &mut self,
+        answer_length: usize,
+    ) -> Result<Vec<T>, HostError<T>> {
+        match self.rng.take() {
+            Some(mut rng) => {
+                let result = self.random_answer(answer_length, &mut rng);
 
-                let mut indices: Vec<usize> = Vec::with_capacity(answer_length);
+                self.rng = Some(rng);
 
-                for i in 0..answer_length {
-                    indices.push(i);
-                }
+                result
+            },
+            #[cfg(feature = "std")]
+            None => self.random_answer(answer_length, &mut rand::rng()),
+            #[cfg(not(feature = "std"))]
+            None => Err(HostError::RngUnseeded),
+        }
+    }
 
-                let mut rng = &mut rand::rng();
-                indices.shuffle(&mut rng);
+    /// Renew this host with an answer drawn from the secret source. The random number generator is chosen the same as `renew_with_random_answer`.
+    pub fn renew_with_source<Src: SecretSource<T>>(
+        &mut self,
+        source: &mut Src,
+    ) -> Result<(), HostError<T>> {
+        match self.rng.take() {
+            Some(mut rng) => {
+                let result = self.renew_with_source_rng(source, &mut rng);
 
-                for i in indices {
-                    answer.push(letters_vec[i].clone());
+                self.rng = Some(rng);
+
+                result
+            },
+            #[cfg(feature = "std")]
+            None => self.renew_with_source_rng(source, &mut rand::rng()),
+            #[cfg(not(feature = "std"))]
+            None => Err(HostError::RngUnseeded),
+        }
+    }
+
+    /// Renew this host with an answer drawn from the secret source by using the given random number generator.
+    pub fn renew_with_source_rng<Src: SecretSource<T>, R: Rng + ?Sized>(
+        &mut self,
+        source: &mut Src,
+        rng: &mut R,
+    ) -> Result<(), HostError<T>> {
+        let answer = source.draw(self, rng)?;
+
+        self.check_known_answer(&answer)?;
+
+        self.set_answer(answer);
+
+        Ok(())
+    }
+
+    /// Renew this host with a random answer generated by the given random number generator.
+    pub fn renew_with_random_answer_rng<R: Rng + ?Sized>(
+        &mut self,
+        answer_length: usize,
+        rng: &mut R,
+    ) -> Result<(), HostError<T>> {
+        let answer = self.random_answer(answer_length, rng)?;
+
+        self.set_answer(answer);
+
+        Ok(())
+    }
+
+    /// Draw an answer uniformly among the answers which this host accepts.
+    pub(crate) fn random_answer<R: Rng + ?Sized>(
+        &self,
+        answer_length: usize,
+        rng: &mut R,
+    ) -> Result<Vec<T>, HostError<T>> {
+        let expected = self.get_answer_length_range();
+
+        if !expected.contains(&answer_length) {
+            Err(HostError::AnswerLengthIncorrect {
+                expected,
+                actual: answer_length,
+            })
+        } else if self.constraints.is_empty() {
+            Ok(self.draw_answer(answer_length, rng))
+        } else if self.constraints.is_unsatisfiable(
+            &self.letters,
+            answer_length,
+            self.duplicated_letters_allowed,
+        ) {
+            Err(HostError::ConstraintsUnsatisfiable)
+        } else {
+            // rejection sampling keeps the distribution uniform among the allowed answers, and it is fast if they are not rare
+            for _ in 0..REJECTION_SAMPLING_ATTEMPTS {
+                let answer = self.draw_answer(answer_length, rng);
+
+                if self.constraints.is_satisfied(&answer) {
+                    return Ok(answer);
                 }
             }
 
-            self.answer = answer;
+            // the allowed answers are rare, so enumerate them and pick one uniformly by reservoir sampling (the k-th allowed answer replaces the picked one with probability 1/k)
+            let mut count = 0usize;
+            let mut picked = None;
 
-            Ok(())
+            let _ = self.for_each_allowed_answer(answer_length, |answer| {
+                count += 1;
+
+                if rng.random_range(0..count) == 0 {
+                    picked = Some(answer.to_vec());
+                }
+
+                ControlFlow::Continue(())
+            });
+
+            match picked {
+                Some(picked) => Ok(picked),
+                None => Err(HostError::ConstraintsUnsatisfiable),
+            }
         }
     }
 
-    /// Renew this host with a known answer.
-    pub fn renew_with_known_answer(&mut self, answer: Vec<T>) -> Result<(), HostError<T>> {
+    /// Draw an answer uniformly without the constraints.
+    fn draw_answer<R: Rng + ?Sized>(&self, answer_length: usize, rng: &mut R) -> Vec<T> {
         let letters = &self.letters;
 
-        let letters_len = letters.len();
-        let answer_length = answer.len();
+        let mut answer: Vec<T> = Vec::with_capacity(answer_length);
 
-        if answer_length == 0 || answer_length > letters_len {
-            Err(HostError::AnswerLengthIncorrect)
+        if self.duplicated_letters_allowed {
+            for _ in 0..answer_length {
+                answer.push(letters.as_slice().choose(rng).unwrap().clone());
+            }
         } else {
-            let mut answer_2: Vec<T> = Vec::with_capacity(answer_length);
+            let mut letters_vec: Vec<&T> = letters.iter().collect();
 
-            for letter in answer {
-                if !letters.contains(&letter) {
-                    return Err(HostError::AnswerContainsIncorrectLetter(letter));
-                }
-                if answer_2.contains(&letter) {
-                    return Err(HostError::AnswerContainsDuplicatedLetter(letter));
-                }
+            // a partial Fisher-Yates shuffle draws every arrangement of `answer_length` letters with the same probability
+            let (picked, _) = letters_vec.partial_shuffle(rng, answer_length);
 
-                answer_2.push(letter);
+            for letter in picked {
+                answer.push((*letter).clone());
             }
+        }
 
-            self.answer = answer_2;
+        answer
+    }
 
-            Ok(())
-        }
+    /// Visit every answer which follows the constraints, until `visit` breaks.
+    fn for_each_allowed_answer(
+        &self,
+        answer_length: usize,
+        mut visit: impl FnMut(&[T]) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let mut answer: Vec<T> = Vec::with_capacity(answer_length);
+
+        constraints::for_each_code(
+            self.letters.len(),
+            answer_length,
+            self.duplicated_letters_allowed,
+            |indices| {
+                answer.clear();
+                answer.extend(indices.iter().map(|&i| self.letters.get(i).unwrap().clone()));
+
+                if self.constraints.is_satisfied(&answer) {
+                    visit(&answer)
+                } else {
+                    ControlFlow::Continue(())
+                }
+            },
+        )
+    }
+
+    /// Renew this host with a known answer.
+    pub fn renew_with_known_answer(&mut self, answer: Vec<T>) -> Result<(), HostError<T>> {
+        self.check_known_answer(&answer)?;
+
+        self.set_answer(answer);
+
+        Ok(())
+    }
+
+    /// Check whether a known answer is valid for this host, so that it can be used later by `renew_with_validated_answer` without checking again.
+    pub fn validate_answer(&self, answer: Vec<T>) -> Result<ValidatedAnswer<T>, HostError<T>> {
+        self.check_known_answer(&answer)?;
+
+        Ok(ValidatedAnswer {
+            answer,
+            #[cfg(feature = "zeroize")]
+            wipe: self.wipe,
+        })
+    }
+
+    /// Renew this host with an answer which has been validated by `validate_answer`. If it was validated by another host with different letters or a different mode, it is caught by a debug assertion.
+    pub fn renew_with_validated_answer(&mut self, answer: ValidatedAnswer<T>) {
+        debug_assert!(
+            self.check_known_answer(&answer.answer).is_ok(),
+            "the answer was validated for another host"
+        );
+
+        self.set_answer(answer.into_inner());
+    }
+
+    /// Renew this host with a known answer which is trusted to be valid, without checking it. An invalid answer makes the replies meaningless (but is never undefined behavior), and it is caught by a debug assertion.
+    pub fn renew_with_trusted_answer(&mut self, answer: Vec<T>) {
+        debug_assert!(self.check_known_answer(&answer).is_ok(), "the trusted answer is invalid");
+
+        self.set_answer(answer);
     }
 
     #[allow(clippy::missing_safety_doc)]
     /// Renew this host with a known answer unsafely.
+    #[deprecated(
+        note = "nothing unsafe happens here; use `Host::renew_with_trusted_answer` instead"
+    )]
     pub unsafe fn renew_with_known_answer_unsafe(&mut self, answer: Vec<T>) {
+        self.renew_with_trusted_answer(answer);
+    }
+
+    /// The range of the lengths of answers which can be made of the letters. If the same letter can be used more than once, it is up to `MAX_ANSWER_LENGTH`.
+    pub fn get_answer_length_range(&self) -> RangeInclusive<usize> {
+        if self.duplicated_letters_allowed {
+            1..=MAX_ANSWER_LENGTH
+        } else {
+            1..=self.letters.len()
+        }
+    }
+
+    pub(crate) fn check_known_answer(&self, answer: &[T]) -> Result<(), HostError<T>> {
+        check_code(
+            &self.letters,
+            self.duplicated_letters_allowed,
+            self.get_answer_length_range(),
+            answer,
+        )?;
+
+        if !self.constraints.is_satisfied(answer) {
+            return Err(HostError::AnswerBreaksConstraints);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn set_answer(&mut self, answer: Vec<T>) {
+        #[cfg(feature = "zeroize")]
+        self.wipe_answer();
+
+        self.packed_answer = PackedCode::new(&self.letters, &answer);
         self.answer = answer;
+
+        // the commitment was for the previous answer
+        #[cfg(feature = "commitment")]
+        {
+            self.salt = None;
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    fn wipe_answer(&mut self) {
+        if let Some(wipe) = self.wipe {
+            self.answer.iter_mut().for_each(wipe);
+
+            if let Some(packed_answer) = self.packed_answer.as_mut() {
+                packed_answer.zeroize();
+            }
+
+            #[cfg(feature = "commitment")]
+            if let Some(salt) = self.salt.as_mut() {
+                salt.zeroize();
+            }
+        }
     }
 }
 
-impl<T: Eq + Hash + Clone> Host<T> {
-    /// Answer for the question. If the format of the input answer is correct, it returns the number of bulls and the number of cows.
-    pub fn answer(&self, answer: &[T]) -> Result<(usize, usize), HostError<T>> {
-        let answer_length = answer.len();
+#[cfg(feature = "commitment")]
+impl<T: Eq + Hash + Clone, S: Scorer<T>> Host<T, S> {
+    /// Start a round by committing the current answer with a fresh random salt, and return the commitment which should be published to the guessers. Renewing the answer discards the commitment. If the answer is invalid (see `from_trusted`), it returns the error and nothing is committed.
+    #[cfg(feature = "std")]
+    pub fn commit(&mut self) -> Result<Commitment, HostError<T>> {
+        let mut salt = [0u8; SALT_LENGTH];
 
-        if answer_length != self.get_answer_length() {
-            Err(HostError::AnswerLengthIncorrect)
-        } else {
-            let mut answer_2: Vec<&T> = Vec::with_capacity(answer_length);
+        rand::rng().fill(&mut salt);
 
-            let mut bulls = 0;
-            let mut cows = 0;
+        self.commit_with_salt(salt)
+    }
 
-            for (i, letter) in answer.iter().enumerate() {
-                if !self.letters.contains(letter) {
-                    return Err(HostError::AnswerContainsIncorrectLetter(letter.clone()));
-                }
-                if answer_2.contains(&letter) {
-                    return Err(HostError::AnswerContainsDuplicatedLetter(letter.clone()));
-                }
+    /// Start a round by committing the current answer with the given salt, which should be unpredictable to the guessers, and return the commitment which should be published to the guessers. Renewing the answer discards the commitment. If the answer is invalid (see `from_trusted`), it returns the error and nothing is committed.
+    pub fn commit_with_salt(
+        &mut self,
+        salt: [u8; SALT_LENGTH],
+    ) -> Result<Commitment, HostError<T>> {
+        // a trusted answer may be invalid, and only an answer made of the letters can be committed
+        self.check_known_answer(&self.answer)?;
 
-                if self.answer[i].eq(letter) {
-                    bulls += 1;
-                } else if self.answer.contains(letter) {
-                    cows += 1;
-                }
+        self.salt = Some(salt);
+
+        Ok(self.get_commitment().unwrap())
+    }
+
+    /// Get the commitment of the current round, if the current answer has been committed.
+    pub fn get_commitment(&self) -> Option<Commitment> {
+        self.salt.as_ref().and_then(|salt| {
+            Commitment::new(&self.letters, self.duplicated_letters_allowed, salt, &self.answer)
+        })
+    }
+
+    /// Reveal the salt and the answer of the current round after the round, so that the guessers can verify it. It returns `None` if the current answer has not been committed.
+    pub fn open_commitment(&self) -> Option<Opening<T>> {
+        #[allow(unused_mut)]
+        let mut opening = self.salt.map(|salt| Opening::new(salt, self.answer.clone()));
+
+        #[cfg(feature = "zeroize")]
+        if let Some(opening) = opening.as_mut() {
+            opening.set_wipe(self.wipe);
+        }
+
+        opening
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: Eq + Hash + Clone, S: Scorer<T>> Drop for Host<T, S> {
+    #[inline]
+    fn drop(&mut self) {
+        self.wipe_answer();
+    }
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> Host<T, S> {
+    /// Answer for the question. If the format of the input answer is correct, it returns the reply decided by the scorer, which is the number of bulls and the number of cows by default.
+    pub fn answer(&self, answer: &[T]) -> Result<S::Reply, HostError<T>> {
+        // a guess which can be packed is made of valid letters without duplicates, so only its length needs checking
+        if let Some(reply) = self.score_packed(answer) {
+            return Ok(reply);
+        }
+
+        self.check_answer(answer)?;
+
+        Ok(self.scorer.score(&self.answer, answer))
+    }
+
+    /// Reply to a guess which has been checked by `check_answer`.
+    pub(crate) fn answer_checked(&self, answer: &[T]) -> S::Reply {
+        match self.score_packed(answer) {
+            Some(reply) => reply,
+            None => self.scorer.score(&self.answer, answer),
+        }
+    }
+
+    fn score_packed(&self, answer: &[T]) -> Option<S::Reply> {
+        let packed_answer = self.packed_answer.as_ref()?;
+
+        if answer.len() != packed_answer.len() {
+            return None;
+        }
+
+        let packed_guess = PackedCode::new(&self.letters, answer)?;
+
+        self.scorer.score_packed(packed_answer, &packed_guess)
+    }
+
+    /// Whether the reply means the guess is the answer.
+    pub fn is_win(&self, reply: &S::Reply) -> bool {
+        self.scorer.is_win(reply, self.get_answer_length())
+    }
+
+    /// Answer for the question in detail. If the format of the input answer is correct, it returns the classification of the letter at every position of the input answer. When a letter appears more times in the input answer than in the answer, the leftmost occurrences are the cows.
+    pub fn answer_detailed(&self, answer: &[T]) -> Result<Vec<Feedback>, HostError<T>> {
+        self.check_answer(answer)?;
+
+        Ok(scorer::make_feedback(&self.answer, answer))
+    }
+
+    /// Answer for many guesses, appending the replies to `replies` in order. If any of the guesses is invalid, it returns the error and nothing is appended.
+    pub fn answer_many<G: AsRef<[T]>>(
+        &self,
+        guesses: &[G],
+        replies: &mut Vec<S::Reply>,
+    ) -> Result<(), HostError<T>> {
+        let start = replies.len();
+
+        replies.reserve(guesses.len());
 
-                answer_2.push(letter);
+        for guess in guesses {
+            match self.answer(guess.as_ref()) {
+                Ok(reply) => replies.push(reply),
+                Err(error) => {
+                    replies.truncate(start);
+
+                    return Err(error);
+                },
             }
+        }
 
-            Ok((bulls, cows))
+        Ok(())
+    }
+
+    /// Reply to a guess as if each of the candidates were the answer, appending the replies to `replies` in order. The guess and the candidates are checked in the same way as guesses. If any of them is invalid, it returns the error and nothing is appended.
+    pub fn score_candidates<C: AsRef<[T]>>(
+        &self,
+        guess: &[T],
+        candidates: &[C],
+        replies: &mut Vec<S::Reply>,
+    ) -> Result<(), HostError<T>> {
+        self.check_answer(guess)?;
+
+        for candidate in candidates {
+            self.check_answer(candidate.as_ref())?;
         }
+
+        replies.reserve(candidates.len());
+
+        scorer::score_candidates(&self.scorer, &self.letters, candidates, None, guess, replies);
+
+        Ok(())
     }
+
+    /// Check a guess and collect every problem in it, instead of stopping at the first one. The problems are in the order of their indices, after the problem of the length.
+    pub fn validate_guess(&self, guess: &[T]) -> Result<(), Vec<HostError<T>>> {
+        let mut problems = Vec::new();
+
+        let _ = find_problems(
+            &self.letters,
+            self.duplicated_letters_allowed,
+            self.get_guess_length_range(),
+            guess,
+            |problem| {
+                problems.push(problem);
+
+                ControlFlow::Continue(())
+            },
+        );
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// The range of the lengths of guesses. It is only the length of the answer, unless the length is hidden.
+    pub(crate) fn get_guess_length_range(&self) -> RangeInclusive<usize> {
+        if self.scorer.is_length_hidden() {
+            self.get_answer_length_range()
+        } else {
+            let answer_length = self.get_answer_length();
+
+            answer_length..=answer_length
+        }
+    }
+
+    pub(crate) fn check_answer(&self, answer: &[T]) -> Result<(), HostError<T>> {
+        check_code(
+            &self.letters,
+            self.duplicated_letters_allowed,
+            self.get_guess_length_range(),
+            answer,
+        )
+    }
+}
+
+/// Report the problems of a code (an answer or a guess) one by one, until `report` breaks.
+pub(crate) fn find_problems<T: Eq + Hash + Clone>(
+    letters: &Alphabet<T>,
+    duplicated_letters_allowed: bool,
+    expected_length: RangeInclusive<usize>,
+    code: &[T],
+    mut report: impl FnMut(HostError<T>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    if !expected_length.contains(&code.len()) {
+        report(HostError::AnswerLengthIncorrect {
+            expected: expected_length,
+            actual:   code.len(),
+        })?;
+    }
+
+    for (index, letter) in code.iter().enumerate() {
+        if !letters.contains(letter) {
+            report(HostError::AnswerContainsIncorrectLetter {
+                index,
+                letter: letter.clone(),
+            })?;
+        } else if !duplicated_letters_allowed {
+            if let Some(first_index) = code[..index].iter().position(|e| e.eq(letter)) {
+                report(HostError::AnswerContainsDuplicatedLetter {
+                    index,
+                    first_index,
+                    letter: letter.clone(),
+                })?;
+            }
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+/// Check a code (an answer or a guess) and return its first problem.
+pub(crate) fn check_code<T: Eq + Hash + Clone>(
+    letters: &Alphabet<T>,
+    duplicated_letters_allowed: bool,
+    expected_length: RangeInclusive<usize>,
+    code: &[T],
+) -> Result<(), HostError<T>> {
+    let mut result = Ok(());
+
+    let _ = find_problems(letters, duplicated_letters_allowed, expected_length, code, |problem| {
+        result = Err(problem);
+
+        ControlFlow::Break(())
+    });
+
+    result
 }
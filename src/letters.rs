@@ -1,17 +1,193 @@
+use alloc::vec::Vec;
+use core::{hash::Hash, ops::RangeInclusive, slice};
+#[cfg(feature = "std")]
 use std::collections::HashSet;
 
+use crate::HostError;
+
+/// An ordered set of letters. Letters keep their insertion order, and every letter is mapped to a dense index (`0..len`) which is its position in that order. Looking up a letter scans the letters, so it takes time linear in the number of letters, which is small in a game.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Alphabet<T> {
+    letters: Vec<T>,
+}
+
+impl<T> Alphabet<T> {
+    /// Create an empty alphabet.
+    #[inline]
+    pub const fn new() -> Alphabet<T> {
+        Alphabet {
+            letters: Vec::new()
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.letters.is_empty()
+    }
+
+    /// Get the letter at the index.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.letters.get(index)
+    }
+
+    /// Iterate over the letters in order.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.letters.iter()
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.letters
+    }
+}
+
+impl<T: Eq> Alphabet<T> {
+    /// Get the index of the letter. It takes time linear in the number of letters.
+    #[inline]
+    pub fn index_of(&self, letter: &T) -> Option<usize> {
+        self.letters.iter().position(|e| e.eq(letter))
+    }
+
+    /// Whether the letter is in this alphabet. It takes time linear in the number of letters.
+    #[inline]
+    pub fn contains(&self, letter: &T) -> bool {
+        self.letters.contains(letter)
+    }
+
+    /// Add a letter to the end. If the letter is already in this alphabet, it returns `false` and nothing changes. It takes time linear in the number of letters, so building an alphabet letter by letter takes quadratic time.
+    #[inline]
+    pub fn insert(&mut self, letter: T) -> bool {
+        if self.contains(&letter) {
+            false
+        } else {
+            self.letters.push(letter);
+
+            true
+        }
+    }
+}
+
+impl<T> Default for Alphabet<T> {
+    #[inline]
+    fn default() -> Self {
+        Alphabet::new()
+    }
+}
+
+impl<T: Eq> FromIterator<T> for Alphabet<T> {
+    /// Collect letters in order. Duplicated letters after the first one are ignored.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut alphabet = Alphabet::new();
+
+        for letter in iter {
+            alphabet.insert(letter);
+        }
+
+        alphabet
+    }
+}
+
+impl<T: Eq> From<Vec<T>> for Alphabet<T> {
+    #[inline]
+    fn from(letters: Vec<T>) -> Self {
+        letters.into_iter().collect()
+    }
+}
+
+impl<T: Eq, const N: usize> From<[T; N]> for Alphabet<T> {
+    #[inline]
+    fn from(letters: [T; N]) -> Self {
+        letters.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash> From<HashSet<T>> for Alphabet<T> {
+    /// The order of the letters is the iteration order of the `HashSet`, which is arbitrary.
+    #[inline]
+    fn from(letters: HashSet<T>) -> Self {
+        Alphabet {
+            letters: letters.into_iter().collect()
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Alphabet<T> {
+    type IntoIter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 /// To generate letters.
 pub struct Letters;
 
 impl Letters {
     /// Generate letters for numbers from 0 to 9.
-    pub fn generate_numeric_letters() -> HashSet<u8> {
-        let mut letters = HashSet::new();
+    pub fn generate_numeric_letters() -> Alphabet<u8> {
+        (0..=9).collect()
+    }
+
+    /// Generate letters for hexadecimal digits, `0` to `9` and `A` to `F`.
+    pub fn generate_hexadecimal_letters() -> Alphabet<char> {
+        ('0'..='9').chain('A'..='F').collect()
+    }
+
+    /// Generate letters for uppercase English letters, `A` to `Z`.
+    pub fn generate_uppercase_letters() -> Alphabet<char> {
+        ('A'..='Z').collect()
+    }
+
+    /// Generate letters for lowercase English letters, `a` to `z`.
+    pub fn generate_lowercase_letters() -> Alphabet<char> {
+        ('a'..='z').collect()
+    }
+
+    /// Generate letters for digits and English letters, `0` to `9`, `A` to `Z` and `a` to `z`.
+    pub fn generate_alphanumeric_letters() -> Alphabet<char> {
+        ('0'..='9').chain('A'..='Z').chain('a'..='z').collect()
+    }
+
+    /// Generate letters for the characters in a range. If the range is empty, it returns `HostError::LettersEmpty`.
+    pub fn from_char_range(range: RangeInclusive<char>) -> Result<Alphabet<char>, HostError<char>> {
+        Letters::from_iter(range)
+    }
+
+    /// Generate letters for the characters of a string, such as `"RGBYOP"`. If the string is empty, it returns `HostError::LettersEmpty`. If a character appears more than once, it returns `HostError::LettersContainsDuplicatedLetter`.
+    pub fn from_chars<S: AsRef<str>>(s: S) -> Result<Alphabet<char>, HostError<char>> {
+        Letters::from_iter(s.as_ref().chars())
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    /// Generate letters in the order of an iterator. If there is no letter, it returns `HostError::LettersEmpty`. If a letter appears more than once, it returns `HostError::LettersContainsDuplicatedLetter`.
+    pub fn from_iter<T: Eq + Hash + Clone, I: IntoIterator<Item = T>>(
+        iter: I,
+    ) -> Result<Alphabet<T>, HostError<T>> {
+        let mut alphabet = Alphabet::new();
 
-        for i in 0..=9 {
-            letters.insert(i);
+        for letter in iter {
+            if alphabet.contains(&letter) {
+                return Err(HostError::LettersContainsDuplicatedLetter(letter));
+            }
+
+            alphabet.insert(letter);
         }
 
-        letters
+        if alphabet.is_empty() {
+            Err(HostError::LettersEmpty)
+        } else {
+            Ok(alphabet)
+        }
     }
 }
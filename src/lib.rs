@@ -14,21 +14,54 @@ The `host` struct can be used independently for generating the question and answ
 ```rust
 let host = bulls_and_cows::Host::build_with_known_answer(bulls_and_cows::Letters::generate_numeric_letters(), vec![1, 2, 3, 4]).unwrap();
 
-assert_eq!((4, 0), host.answer(&[1, 2, 3, 4]).unwrap());
-assert_eq!((2, 2), host.answer(&[1, 2, 4, 3]).unwrap());
-assert_eq!((0, 4), host.answer(&[4, 3, 2, 1]).unwrap());
-assert_eq!((0, 0), host.answer(&[5, 6, 7, 8]).unwrap());
+assert_eq!("4A0B", host.answer(&[1, 2, 3, 4]).unwrap().to_string());
+assert_eq!("2A2B", host.answer(&[1, 2, 4, 3]).unwrap().to_string());
+assert_eq!("0A4B", host.answer(&[4, 3, 2, 1]).unwrap().to_string());
+assert_eq!("0A0B", host.answer(&[5, 6, 7, 8]).unwrap().to_string());
 ```
 
 If you want to build up a more complete game stage, use the `play` module. You can see the example `custom_2_players` to learn how to do that.
+
+
+## No `std`
+
+Without the default `std` feature, this crate is `no_std` and only needs `alloc`. Hosts, scorers, the solver and `ABParser` still work, but computer players do not sleep for their thinking delays, and there is no source of entropy, so random answers and computer guesses need a seed (`set_seed`) or a random number generator. Otherwise they return `HostError::RngUnseeded`.
+
+## Wiping Answers
+
+With the `zeroize` feature, a host can wipe its answer from memory when the answer is renewed and when the host is dropped. Wiping is opt-in for every host: call `set_zeroize(true)` on a `Host` or a `FixedHost` whose letters implement `Zeroize`. A `FixedHost` built by `FixedHost::from_host` keeps the setting of the `Host`.
+
+Turning the feature on does not wipe anything by itself. A host with wiping enabled covers its answer (and, with the `commitment` feature, its salt), the answers it validates with `validate_answer`, the openings it makes with `open_commitment`, and a `FixedHost` built from it. The copies which are not covered are:
+
+* answers read by `get_answer` and `into_inner`, and any other copies made by the caller, including the answers given to `renew_with_known_answer` and the other builders before they were moved in,
+* answers held by a `SecretSource`, such as the lists of `WeightedList` and the pools of `WithoutReplacement`,
+* openings created with `Opening::new`, and the letters of an answer reported in a `HostError`.
 */
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "commitment")]
+pub mod commitment;
+mod constraints;
+mod fixed_host;
 mod host;
 mod letters;
+mod multi_host;
 pub mod parser;
 pub mod play;
+mod reveal;
+mod score;
+pub mod scorer;
+pub mod source;
 
 pub use self::{
-    host::{Host, HostError},
-    letters::Letters,
+    constraints::Constraints,
+    fixed_host::FixedHost,
+    host::{Host, HostError, ValidatedAnswer, MAX_ANSWER_LENGTH},
+    letters::{Alphabet, Letters},
+    multi_host::MultiHost,
+    reveal::Revealed,
+    score::{Feedback, Score},
 };
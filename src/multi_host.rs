@@ -0,0 +1,152 @@
+use alloc::{vec, vec::Vec};
+use core::hash::Hash;
+
+use rand::Rng;
+
+use crate::{
+    scorer::{BullsAndCows, Scorer},
+    Alphabet, Host, HostError,
+};
+
+/// A game host with several independent answers (like Quordle). Every guess is checked once and replied for all of the answers, and the round ends when all of them are solved.
+#[derive(Debug)]
+pub struct MultiHost<T: Eq + Hash + Clone, S: Scorer<T> = BullsAndCows> {
+    hosts:  Vec<Host<T, S>>,
+    solved: Vec<bool>,
+}
+
+impl<T: Eq + Hash + Clone> MultiHost<T> {
+    /// Build a multi-answer bulls-and-cows game host with `count` random answers.
+    pub fn build_with_random_answers(
+        letters: Alphabet<T>,
+        answer_length: usize,
+        count: usize,
+    ) -> Result<MultiHost<T>, HostError<T>> {
+        let mut hosts = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            hosts.push(Host::build_with_random_answer(letters.clone(), answer_length)?);
+        }
+
+        MultiHost::from_hosts(hosts)
+    }
+
+    /// Build a multi-answer bulls-and-cows game host with known answers.
+    pub fn build_with_known_answers(
+        letters: Alphabet<T>,
+        answers: Vec<Vec<T>>,
+    ) -> Result<MultiHost<T>, HostError<T>> {
+        let mut hosts = Vec::with_capacity(answers.len());
+
+        for answer in answers {
+            hosts.push(Host::build_with_known_answer(letters.clone(), answer)?);
+        }
+
+        MultiHost::from_hosts(hosts)
+    }
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> MultiHost<T, S> {
+    /// Build a multi-answer game host from hosts, one for every answer. The hosts must have the same letters and mode, and accept guesses of the same lengths. Otherwise, or if there are no hosts, it returns `HostError::HostsIncompatible`.
+    pub fn from_hosts(hosts: Vec<Host<T, S>>) -> Result<MultiHost<T, S>, HostError<T>> {
+        let first = hosts.first().ok_or(HostError::HostsIncompatible)?;
+
+        let compatible = hosts.iter().all(|host| {
+            host.get_letters() == first.get_letters()
+                && host.is_duplicated_letters_allowed() == first.is_duplicated_letters_allowed()
+                && host.get_guess_length_range() == first.get_guess_length_range()
+        });
+
+        if !compatible {
+            return Err(HostError::HostsIncompatible);
+        }
+
+        let solved = vec![false; hosts.len()];
+
+        Ok(MultiHost {
+            hosts,
+            solved,
+        })
+    }
+
+    pub fn get_hosts(&self) -> &[Host<T, S>] {
+        &self.hosts
+    }
+
+    /// The number of answers.
+    pub fn len(&self) -> usize {
+        self.hosts.len()
+    }
+
+    /// It is always `false`, because a multi-answer host has at least one answer.
+    pub fn is_empty(&self) -> bool {
+        self.hosts.is_empty()
+    }
+
+    /// Whether every answer has been guessed since the round started.
+    pub fn get_solved(&self) -> &[bool] {
+        &self.solved
+    }
+
+    /// Whether all of the answers have been guessed, which means the round ends.
+    pub fn is_all_solved(&self) -> bool {
+        self.solved.iter().all(|&solved| solved)
+    }
+
+    /// Start a new round with random answers for every host. If any of the hosts fails to draw an answer, it returns the error and the current round goes on.
+    pub fn renew_with_random_answers(&mut self, answer_length: usize) -> Result<(), HostError<T>> {
+        let answers = self
+            .hosts
+            .iter_mut()
+            .map(|host| host.draw_random_answer(answer_length))
+            .collect::<Result<Vec<Vec<T>>, HostError<T>>>()?;
+
+        self.set_answers(answers);
+
+        Ok(())
+    }
+
+    /// Start a new round with random answers for every host generated by the given random number generator. If any of the hosts fails to draw an answer, it returns the error and the current round goes on.
+    pub fn renew_with_random_answers_rng<R: Rng + ?Sized>(
+        &mut self,
+        answer_length: usize,
+        rng: &mut R,
+    ) -> Result<(), HostError<T>> {
+        let answers = self
+            .hosts
+            .iter()
+            .map(|host| host.random_answer(answer_length, rng))
+            .collect::<Result<Vec<Vec<T>>, HostError<T>>>()?;
+
+        self.set_answers(answers);
+
+        Ok(())
+    }
+
+    /// Renew every host with its answer, after all of the answers have been drawn.
+    fn set_answers(&mut self, answers: Vec<Vec<T>>) {
+        for (host, answer) in self.hosts.iter_mut().zip(answers) {
+            host.set_answer(answer);
+        }
+
+        self.solved.fill(false);
+    }
+
+    /// Answer for the question. If the format of the input answer is correct, it returns the replies for all of the answers in order, and the answers which the input answer hits are marked as solved.
+    pub fn answer(&mut self, answer: &[T]) -> Result<Vec<S::Reply>, HostError<T>> {
+        self.hosts[0].check_answer(answer)?;
+
+        let replies: Vec<S::Reply> =
+            self.hosts.iter().map(|host| host.answer_checked(answer)).collect();
+
+        for ((host, reply), solved) in
+            self.hosts.iter().zip(replies.iter()).zip(self.solved.iter_mut())
+        {
+            if host.is_win(reply) {
+                *solved = true;
+            }
+        }
+
+        Ok(replies)
+    }
+}
@@ -1,44 +1,194 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+#[cfg(feature = "regex")]
 use regex::Regex;
 
-/// A parser to parse `XAYB` strings.
+#[cfg(not(feature = "regex"))]
+use super::hand_written;
+use super::{Notation, ParseError};
+use crate::Score;
+
+/// A parser to parse `XAYB` strings, or replies in other notations.
+///
+/// Strings are matched by regular expressions if the `regex` feature is enabled, or by a hand-written matcher which accepts the same strings otherwise.
 #[derive(Debug)]
 pub struct ABParser {
-    regex: Regex,
+    notations:     Vec<Notation>,
+    #[cfg(feature = "regex")]
+    regexes:       Vec<Regex>,
+    answer_length: Option<usize>,
 }
 
 impl ABParser {
-    /// Create a new `ABParser` instance.
+    /// Create a new `ABParser` instance which accepts the `XAYB` notation.
     pub fn new() -> ABParser {
-        let regex = Regex::new(r"^(\d+)[ ]*[aA][ ]*(\d+)[ ]*[bB]$").unwrap();
+        ABParser::with_notations(&[Notation::AB])
+    }
+
+    /// Create a new `ABParser` instance which accepts the given notations. When a string can be parsed in more than one notation, the first one in `notations` is used.
+    pub fn with_notations(notations: &[Notation]) -> ABParser {
+        #[cfg(feature = "regex")]
+        let regexes = notations
+            .iter()
+            .map(|notation| {
+                let regex = match notation {
+                    Notation::AB => r"^([0-9]+)[ ]*[aA](?:[ ]*([0-9]+)[ ]*[bB])?$",
+                    Notation::Words => {
+                        r"^([0-9]+)[ ]*(?i-u:bulls?)[ ]*,?[ ]*([0-9]+)[ ]*(?i-u:cows?)$"
+                    },
+                    Notation::BC => r"^([0-9]+)[ ]*[bB][ ]*([0-9]+)[ ]*[cC]$",
+                    Notation::PrefixedBC => r"^[bB][ ]*([0-9]+)[ ]*[cC][ ]*([0-9]+)$",
+                    Notation::Pegs => r"^(?:(\+*)(-*)|0)$",
+                };
+
+                Regex::new(regex).unwrap()
+            })
+            .collect();
 
         ABParser {
-            regex,
+            notations: notations.to_vec(),
+            #[cfg(feature = "regex")]
+            regexes,
+            answer_length: None,
         }
     }
+
+    /// Create a new `ABParser` instance which accepts all notations.
+    pub fn with_all_notations() -> ABParser {
+        ABParser::with_notations(&Notation::ALL)
+    }
+
+    /// Create a new `ABParser` instance which accepts the `XAYB` notation and rejects scores which are impossible for an answer with the length of `answer_length`.
+    pub fn with_answer_length(answer_length: usize) -> ABParser {
+        let mut parser = ABParser::new();
+
+        parser.set_answer_length(Some(answer_length));
+
+        parser
+    }
 }
 
 impl ABParser {
-    /// Parse a `XAYB` string in order to get the `X` and the `Y`.
-    pub fn parse<S: AsRef<str>>(&self, s: S) -> Option<(usize, usize)> {
-        let captures = self.regex.captures(s.as_ref().trim());
-
-        match captures {
-            Some(captures) => {
-                let a = match captures[1].parse::<usize>() {
-                    Ok(a) => a,
-                    Err(_) => return None,
-                };
+    pub fn get_answer_length(&self) -> Option<usize> {
+        self.answer_length
+    }
 
-                let b = match captures[2].parse::<usize>() {
-                    Ok(b) => b,
-                    Err(_) => return None,
-                };
+    /// Set the length of the answer. If it is set, scores which are impossible for that length are rejected.
+    pub fn set_answer_length(&mut self, answer_length: Option<usize>) {
+        self.answer_length = answer_length;
+    }
+}
 
-                Some((a, b))
+impl ABParser {
+    /// Parse a `XAYB` string (or a string in another accepted notation) in order to get the `X` and the `Y`.
+    #[inline]
+    pub fn parse<S: AsRef<str>>(&self, s: S) -> Result<Score, ParseError> {
+        self.parse_with_notation(s).map(|(score, _)| score)
+    }
+
+    /// Parse a string in order to get the score and the notation it is written in, so that a reply can be echoed back in the same notation. The echo is normalised by `Notation::format`, so `1A` is echoed as `1A0B` and `1 bull, 2 cows` as `1 bull 2 cows`.
+    pub fn parse_with_notation<S: AsRef<str>>(
+        &self,
+        s: S,
+    ) -> Result<(Score, Notation), ParseError> {
+        let s = s.as_ref();
+
+        // spans are about the original string, so remember how many bytes are trimmed at the start
+        let offset = s.len() - s.trim_start().len();
+        let s = s.trim();
+        let span = offset..(offset + s.len());
+
+        if s.is_empty() {
+            return Err(ParseError::Empty {
+                span,
+            });
+        }
+
+        for (i, notation) in self.notations.iter().enumerate() {
+            let [a, b] = match self.match_notation(i, s) {
+                Some(spans) => spans,
+                None => continue,
+            };
+
+            let score = if *notation == Notation::Pegs {
+                Score::new(a.map(|a| a.len()).unwrap_or(0), b.map(|b| b.len()).unwrap_or(0))
+            } else {
+                Score::new(self.parse_number(s, a, offset)?, self.parse_number(s, b, offset)?)
+            };
+
+            self.check_score(score, span)?;
+
+            return Ok((score, *notation));
+        }
+
+        if self.notations.contains(&Notation::AB) {
+            Err(diagnose_ab(s, offset))
+        } else {
+            Err(ParseError::Unrecognized {
+                span,
+            })
+        }
+    }
+
+    /// Match a trimmed string in the `i`-th notation. The returned spans are the two numbers (or the two runs of pegs) in order. A missing number is `None`.
+    #[cfg(feature = "regex")]
+    fn match_notation(&self, i: usize, s: &str) -> Option<[Option<Range<usize>>; 2]> {
+        self.regexes[i].captures(s).map(|captures| {
+            [captures.get(1).map(|m| m.range()), captures.get(2).map(|m| m.range())]
+        })
+    }
+
+    /// Match a trimmed string in the `i`-th notation. The returned spans are the two numbers (or the two runs of pegs) in order. A missing number is `None`.
+    #[cfg(not(feature = "regex"))]
+    #[inline]
+    fn match_notation(&self, i: usize, s: &str) -> Option<[Option<Range<usize>>; 2]> {
+        hand_written::match_notation(self.notations[i], s)
+    }
+
+    /// Parse the number at the span of the trimmed string. A missing number means `0`.
+    fn parse_number(
+        &self,
+        s: &str,
+        span: Option<Range<usize>>,
+        offset: usize,
+    ) -> Result<usize, ParseError> {
+        match span {
+            Some(span) => {
+                let number = &s[span.clone()];
+                let span = (offset + span.start)..(offset + span.end);
+
+                // numbers larger than the length of the answer are reported by `check_score`, the same as in the other notations
+                number.parse::<usize>().map_err(|_| ParseError::NumberOutOfRange {
+                    span,
+                })
             },
-            None => None,
+            None => Ok(0),
         }
     }
+
+    fn check_score(&self, score: Score, span: Range<usize>) -> Result<(), ParseError> {
+        if let Some(answer_length) = self.answer_length {
+            match score.bulls.checked_add(score.cows) {
+                Some(sum) if sum <= answer_length => {
+                    if !score.is_valid(answer_length) {
+                        return Err(ParseError::Impossible {
+                            span,
+                            score,
+                        });
+                    }
+                },
+                _ => {
+                    return Err(ParseError::TooManyLetters {
+                        span,
+                        score,
+                    })
+                },
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for ABParser {
@@ -47,3 +197,72 @@ impl Default for ABParser {
         ABParser::new()
     }
 }
+
+/// Find out why a trimmed string `s` is not in the `XAYB` notation.
+fn diagnose_ab(s: &str, offset: usize) -> ParseError {
+    let bytes = s.as_bytes();
+
+    let mut p = 0;
+
+    // the span of the character at `p`, or an empty span at the end
+    let here = |p: usize| {
+        let len = s[p..].chars().next().map(|c| c.len_utf8()).unwrap_or(0);
+
+        (offset + p)..(offset + p + len)
+    };
+
+    let skip_digits = |p: usize| p + bytes[p..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let skip_spaces = |p: usize| p + bytes[p..].iter().take_while(|&&b| b == b' ').count();
+
+    let q = skip_digits(p);
+
+    if q == p {
+        return ParseError::MissingNumber {
+            span: here(p)
+        };
+    }
+
+    p = skip_spaces(q);
+
+    if !matches!(bytes.get(p), Some(b'a' | b'A')) {
+        return ParseError::MissingA {
+            span: here(p)
+        };
+    }
+
+    p = skip_spaces(p + 1);
+
+    let q = skip_digits(p);
+
+    if q == p {
+        return if matches!(bytes.get(p), Some(b'b' | b'B')) {
+            ParseError::MissingNumber {
+                span: here(p)
+            }
+        } else {
+            ParseError::TrailingGarbage {
+                span: (offset + p)..(offset + s.len())
+            }
+        };
+    }
+
+    p = skip_spaces(q);
+
+    if !matches!(bytes.get(p), Some(b'b' | b'B')) {
+        return ParseError::MissingB {
+            span: here(p)
+        };
+    }
+
+    p += 1;
+
+    if p < s.len() {
+        ParseError::TrailingGarbage {
+            span: (offset + p)..(offset + s.len())
+        }
+    } else {
+        ParseError::Unrecognized {
+            span: offset..(offset + s.len())
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::Range,
+};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::Alphabet;
+
+/// A converter between text and letters.
+pub trait Codec<T> {
+    /// Decode text into letters.
+    fn decode(&self, s: &str) -> Result<Vec<T>, CodecError>;
+
+    /// Encode letters into text.
+    fn encode(&self, letters: &[T]) -> String;
+}
+
+/// The error returned when text cannot be decoded into letters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodecError {
+    /// The index of the bad token among all tokens.
+    pub position: usize,
+    /// The byte range of the bad token in the text.
+    pub span:     Range<usize>,
+    /// The bad token. It is empty if a letter is missing, such as `1,,2`.
+    pub token:    String,
+}
+
+impl Display for CodecError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        if self.token.is_empty() {
+            f.write_fmt(format_args!("A letter is missing at position {}.", self.position))
+        } else {
+            f.write_fmt(format_args!(
+                "`{}` at position {} is not a letter.",
+                self.token, self.position
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CodecError {}
+
+/// A codec which writes every letter of an alphabet as a symbol.
+///
+/// Decoding accepts letters separated by commas (`R,G,B,Y`), by whitespaces (`1 2 3 4`), or not separated at all (`1234`). In the last case, the longest symbol is matched first. Letters which are not in the alphabet are encoded as `?`.
+#[derive(Debug, Clone)]
+pub struct AlphabetCodec<T> {
+    alphabet:  Alphabet<T>,
+    symbols:   Vec<String>,
+    separator: String,
+}
+
+impl<T: Eq + Clone + Display> AlphabetCodec<T> {
+    /// Create a new `AlphabetCodec` instance whose symbols are the `Display` forms of the letters.
+    pub fn new(alphabet: Alphabet<T>) -> AlphabetCodec<T> {
+        AlphabetCodec::with_symbols(alphabet, |letter| letter.to_string())
+    }
+}
+
+impl<T: Eq + Clone> AlphabetCodec<T> {
+    /// Create a new `AlphabetCodec` instance whose symbols are made by a function. When encoding, letters are not separated if every symbol is a single character, or separated by a space otherwise.
+    pub fn with_symbols<F: FnMut(&T) -> String>(alphabet: Alphabet<T>, f: F) -> AlphabetCodec<T> {
+        let symbols: Vec<String> = alphabet.iter().map(f).collect();
+
+        let separator =
+            if symbols.iter().all(|symbol| symbol.chars().count() == 1) { "" } else { " " };
+
+        AlphabetCodec {
+            alphabet,
+            symbols,
+            separator: separator.to_string(),
+        }
+    }
+
+    /// Set the separator used for encoding.
+    pub fn set_separator<S: Into<String>>(&mut self, separator: S) {
+        self.separator = separator.into();
+    }
+
+    pub fn get_alphabet(&self) -> &Alphabet<T> {
+        &self.alphabet
+    }
+
+    fn find_letter(&self, token: &str) -> Option<T> {
+        self.symbols
+            .iter()
+            .position(|symbol| symbol == token)
+            .map(|index| self.alphabet.get(index).unwrap().clone())
+    }
+
+    fn decode_tokens<'a, I: Iterator<Item = (usize, &'a str)>>(
+        &self,
+        tokens: I,
+    ) -> Result<Vec<T>, CodecError> {
+        let mut letters = Vec::new();
+
+        for (position, (start, token)) in tokens.enumerate() {
+            match self.find_letter(token) {
+                Some(letter) => letters.push(letter),
+                None => {
+                    return Err(CodecError {
+                        position,
+                        span: start..(start + token.len()),
+                        token: token.to_string(),
+                    })
+                },
+            }
+        }
+
+        Ok(letters)
+    }
+
+    fn decode_unseparated(&self, s: &str, offset: usize) -> Result<Vec<T>, CodecError> {
+        let mut letters = Vec::new();
+
+        let mut start = 0;
+
+        while start < s.len() {
+            let rest = &s[start..];
+
+            let matched = self
+                .symbols
+                .iter()
+                .enumerate()
+                .filter(|(_, symbol)| !symbol.is_empty() && rest.starts_with(symbol.as_str()))
+                .max_by_key(|(_, symbol)| symbol.len());
+
+            match matched {
+                Some((index, symbol)) => {
+                    letters.push(self.alphabet.get(index).unwrap().clone());
+
+                    start += symbol.len();
+                },
+                None => {
+                    let c = rest.chars().next().unwrap();
+
+                    return Err(CodecError {
+                        position: letters.len(),
+                        span:     (offset + start)..(offset + start + c.len_utf8()),
+                        token:    c.to_string(),
+                    });
+                },
+            }
+        }
+
+        Ok(letters)
+    }
+}
+
+impl<T: Eq + Clone> Codec<T> for AlphabetCodec<T> {
+    fn decode(&self, s: &str) -> Result<Vec<T>, CodecError> {
+        let trimmed = s.trim();
+        let offset = s.len() - s.trim_start().len();
+
+        if trimmed.contains(',') {
+            let mut start = offset;
+
+            self.decode_tokens(trimmed.split(',').map(|token| {
+                let token_start = start + (token.len() - token.trim_start().len());
+
+                start += token.len() + 1;
+
+                (token_start, token.trim())
+            }))
+        } else if trimmed.contains(char::is_whitespace) {
+            self.decode_tokens(trimmed.split_whitespace().map(|token| {
+                // `token` is a sub-slice of `s`, so the pointer difference is its byte offset
+                (token.as_ptr() as usize - s.as_ptr() as usize, token)
+            }))
+        } else {
+            self.decode_unseparated(trimmed, offset)
+        }
+    }
+
+    fn encode(&self, letters: &[T]) -> String {
+        let mut s = String::new();
+
+        for (i, letter) in letters.iter().enumerate() {
+            if i > 0 {
+                s.push_str(&self.separator);
+            }
+
+            match self.alphabet.index_of(letter) {
+                Some(index) => s.push_str(&self.symbols[index]),
+                None => s.push('?'),
+            }
+        }
+
+        s
+    }
+}
@@ -0,0 +1,113 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::Range,
+};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::Score;
+
+/// The possible errors for parsing replies. Every error carries the byte span in the input string which the error is about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string is empty.
+    Empty { span: Range<usize> },
+    /// A number is expected.
+    MissingNumber { span: Range<usize> },
+    /// The `A` after the number of bulls is missing.
+    MissingA { span: Range<usize> },
+    /// The `B` after the number of cows is missing.
+    MissingB { span: Range<usize> },
+    /// A number is too large to be represented.
+    NumberOutOfRange { span: Range<usize> },
+    /// There are unexpected characters after the reply.
+    TrailingGarbage { span: Range<usize> },
+    /// The string is not in any accepted notation.
+    Unrecognized { span: Range<usize> },
+    /// There are more bulls and cows than letters in the answer.
+    TooManyLetters { span: Range<usize>, score: Score },
+    /// The score can never happen, such as `(n-1)A1B`.
+    Impossible { span: Range<usize>, score: Score },
+}
+
+impl ParseError {
+    /// Get the byte span in the input string which this error is about.
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            ParseError::Empty {
+                span,
+            }
+            | ParseError::MissingNumber {
+                span,
+            }
+            | ParseError::MissingA {
+                span,
+            }
+            | ParseError::MissingB {
+                span,
+            }
+            | ParseError::NumberOutOfRange {
+                span,
+            }
+            | ParseError::TrailingGarbage {
+                span,
+            }
+            | ParseError::Unrecognized {
+                span,
+            }
+            | ParseError::TooManyLetters {
+                span, ..
+            }
+            | ParseError::Impossible {
+                span, ..
+            } => span.clone(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ParseError::Empty {
+                ..
+            } => f.write_str("The reply is empty."),
+            ParseError::MissingNumber {
+                span,
+            } => f.write_fmt(format_args!("A number is expected at {}.", span.start)),
+            ParseError::MissingA {
+                span,
+            } => f.write_fmt(format_args!("`A` is expected at {}.", span.start)),
+            ParseError::MissingB {
+                span,
+            } => f.write_fmt(format_args!("`B` is expected at {}.", span.start)),
+            ParseError::NumberOutOfRange {
+                span,
+            } => f.write_fmt(format_args!(
+                "The number at {}..{} is out of range.",
+                span.start, span.end
+            )),
+            ParseError::TrailingGarbage {
+                span,
+            } => f.write_fmt(format_args!(
+                "There are unexpected characters at {}..{}.",
+                span.start, span.end
+            )),
+            ParseError::Unrecognized {
+                ..
+            } => f.write_str("The reply is not in an accepted notation."),
+            ParseError::TooManyLetters {
+                score, ..
+            } => f.write_fmt(format_args!(
+                "{} has more bulls and cows than letters in the answer.",
+                score
+            )),
+            ParseError::Impossible {
+                score, ..
+            } => f.write_fmt(format_args!("{} can never happen.", score)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseError {}
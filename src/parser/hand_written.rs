@@ -0,0 +1,215 @@
+use core::ops::Range;
+
+use super::Notation;
+
+/// A cursor over a trimmed ASCII-oriented string.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    p:     usize,
+}
+
+impl<'a> Cursor<'a> {
+    #[inline]
+    fn new(s: &'a str) -> Cursor<'a> {
+        Cursor {
+            bytes: s.as_bytes(), p: 0
+        }
+    }
+
+    #[inline]
+    fn is_end(&self) -> bool {
+        self.p == self.bytes.len()
+    }
+
+    /// Skip `[ ]*`.
+    #[inline]
+    fn spaces(&mut self) {
+        while self.bytes.get(self.p) == Some(&b' ') {
+            self.p += 1;
+        }
+    }
+
+    /// Match `[0-9]+`.
+    #[inline]
+    fn digits(&mut self) -> Option<Range<usize>> {
+        self.run(|b| b.is_ascii_digit())
+    }
+
+    /// Match `[x]*` and return the span, even if it is empty.
+    #[inline]
+    fn repeat(&mut self, x: u8) -> Range<usize> {
+        let start = self.p;
+
+        while self.bytes.get(self.p) == Some(&x) {
+            self.p += 1;
+        }
+
+        start..self.p
+    }
+
+    /// Match one or more bytes which satisfy `f`.
+    #[inline]
+    fn run<F: Fn(u8) -> bool>(&mut self, f: F) -> Option<Range<usize>> {
+        let start = self.p;
+
+        while self.bytes.get(self.p).is_some_and(|&b| f(b)) {
+            self.p += 1;
+        }
+
+        if self.p > start {
+            Some(start..self.p)
+        } else {
+            None
+        }
+    }
+
+    /// Match a byte case-insensitively.
+    #[inline]
+    fn byte(&mut self, x: u8) -> bool {
+        if self.bytes.get(self.p).is_some_and(|b| b.eq_ignore_ascii_case(&x)) {
+            self.p += 1;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Match a word case-insensitively.
+    #[inline]
+    fn word(&mut self, word: &[u8]) -> bool {
+        let end = self.p + word.len();
+
+        if self.bytes.get(self.p..end).is_some_and(|s| s.eq_ignore_ascii_case(word)) {
+            self.p = end;
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Match a trimmed string in a notation. The returned spans are the two numbers (or the two runs of pegs) in order. A missing number is `None`.
+pub(crate) fn match_notation(notation: Notation, s: &str) -> Option<[Option<Range<usize>>; 2]> {
+    let mut c = Cursor::new(s);
+
+    let spans = match notation {
+        // ([0-9]+)[ ]*[aA](?:[ ]*([0-9]+)[ ]*[bB])?
+        Notation::AB => {
+            let a = c.digits()?;
+
+            c.spaces();
+
+            if !c.byte(b'a') {
+                return None;
+            }
+
+            if c.is_end() {
+                [Some(a), None]
+            } else {
+                c.spaces();
+
+                let b = c.digits()?;
+
+                c.spaces();
+
+                if !c.byte(b'b') {
+                    return None;
+                }
+
+                [Some(a), Some(b)]
+            }
+        },
+        // ([0-9]+)[ ]*bulls?[ ]*,?[ ]*([0-9]+)[ ]*cows?
+        Notation::Words => {
+            let a = c.digits()?;
+
+            c.spaces();
+
+            if !c.word(b"bull") {
+                return None;
+            }
+
+            c.byte(b's');
+            c.spaces();
+            c.byte(b',');
+            c.spaces();
+
+            let b = c.digits()?;
+
+            c.spaces();
+
+            if !c.word(b"cow") {
+                return None;
+            }
+
+            c.byte(b's');
+
+            [Some(a), Some(b)]
+        },
+        // ([0-9]+)[ ]*[bB][ ]*([0-9]+)[ ]*[cC]
+        Notation::BC => {
+            let a = c.digits()?;
+
+            c.spaces();
+
+            if !c.byte(b'b') {
+                return None;
+            }
+
+            c.spaces();
+
+            let b = c.digits()?;
+
+            c.spaces();
+
+            if !c.byte(b'c') {
+                return None;
+            }
+
+            [Some(a), Some(b)]
+        },
+        // [bB][ ]*([0-9]+)[ ]*[cC][ ]*([0-9]+)
+        Notation::PrefixedBC => {
+            if !c.byte(b'b') {
+                return None;
+            }
+
+            c.spaces();
+
+            let a = c.digits()?;
+
+            c.spaces();
+
+            if !c.byte(b'c') {
+                return None;
+            }
+
+            c.spaces();
+
+            let b = c.digits()?;
+
+            [Some(a), Some(b)]
+        },
+        // (\+*)(-*)|0
+        Notation::Pegs => {
+            if s == "0" {
+                c.p = 1;
+
+                [None, None]
+            } else {
+                let a = c.repeat(b'+');
+                let b = c.repeat(b'-');
+
+                [Some(a), Some(b)]
+            }
+        },
+    };
+
+    if c.is_end() {
+        Some(spans)
+    } else {
+        None
+    }
+}
@@ -1,3 +1,13 @@
 mod ab_parser;
+mod codec;
+mod error;
+#[cfg(not(feature = "regex"))]
+mod hand_written;
+mod notation;
 
-pub use self::ab_parser::ABParser;
+pub use self::{
+    ab_parser::ABParser,
+    codec::{AlphabetCodec, Codec, CodecError},
+    error::ParseError,
+    notation::Notation,
+};
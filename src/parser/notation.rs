@@ -0,0 +1,63 @@
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+use crate::Score;
+
+/// A notation of replies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// `1A2B`. The cows can be omitted when parsing, such as `1A`, but a score is always formatted with them, such as `1A0B`.
+    AB,
+    /// `1 bull 2 cows`.
+    Words,
+    /// `1B2C`.
+    BC,
+    /// `B1 C2`.
+    PrefixedBC,
+    /// `++-`, a `+` for every bull and a `-` for every cow. `0` means there is neither.
+    Pegs,
+}
+
+impl Notation {
+    /// All notations.
+    pub const ALL: [Notation; 5] =
+        [Notation::AB, Notation::Words, Notation::BC, Notation::PrefixedBC, Notation::Pegs];
+
+    /// Format a score in this notation.
+    pub fn format(&self, score: Score) -> String {
+        let Score {
+            bulls,
+            cows,
+        } = score;
+
+        match self {
+            Notation::AB => format!("{}A{}B", bulls, cows),
+            Notation::Words => format!(
+                "{} {} {} {}",
+                bulls,
+                if bulls == 1 { "bull" } else { "bulls" },
+                cows,
+                if cows == 1 { "cow" } else { "cows" }
+            ),
+            Notation::BC => format!("{}B{}C", bulls, cows),
+            Notation::PrefixedBC => format!("B{} C{}", bulls, cows),
+            Notation::Pegs => {
+                if bulls == 0 && cows == 0 {
+                    return String::from("0");
+                }
+
+                let mut s = String::with_capacity(bulls + cows);
+
+                for _ in 0..bulls {
+                    s.write_char('+').unwrap();
+                }
+
+                for _ in 0..cows {
+                    s.write_char('-').unwrap();
+                }
+
+                s
+            },
+        }
+    }
+}
@@ -1,45 +1,67 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{cell::RefCell, hash::Hash, iter, ops::RangeInclusive};
+#[cfg(feature = "std")]
 use std::{
-    collections::HashSet,
-    hash::Hash,
     thread,
     time::{Duration, Instant},
 };
 
 use permutohedron::Heap;
-use random_pick;
+use rand::{seq::IndexedRandom, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 
 use super::{Guesser, Questioner};
-use crate::{Host, HostError};
+use crate::{
+    scorer::{self, BullsAndCows, PackedCode, Scorer},
+    Alphabet, Host, HostError, MultiHost,
+};
 
 /// A questioner controlled by a computer.
 #[derive(Debug)]
-pub struct ComputerQuestioner<T: Eq + Hash + Clone> {
-    host:           Host<T>,
+pub struct ComputerQuestioner<T: Eq + Hash + Clone, S: Scorer<T> = BullsAndCows> {
+    host:           Host<T, S>,
     thinking_delay: u64,
 }
 
-impl<T: Eq + Hash + Clone> ComputerQuestioner<T> {
-    /// Create a new computer player as a questioner. The `thinking_delay` is a value which simulates the time in milliseconds that a human player needs to take to think.
-    pub fn new(host: Host<T>, thinking_delay: u64) -> ComputerQuestioner<T> {
-        ComputerQuestioner {
+impl<T: Eq + Hash + Clone, S: Scorer<T>> ComputerQuestioner<T, S> {
+    /// Create a new computer player as a questioner. The `thinking_delay` is a value which simulates the time in milliseconds that a human player needs to take to think. It only takes effect with the `std` feature. Without the `std` feature there is no source of entropy for new questions, so the host has to be seeded (see `Host::set_seed`), or it returns `HostError::RngUnseeded`.
+    pub fn new(
+        host: Host<T, S>,
+        thinking_delay: u64,
+    ) -> Result<ComputerQuestioner<T, S>, HostError<T>> {
+        #[cfg(not(feature = "std"))]
+        if !host.is_seeded() {
+            return Err(HostError::RngUnseeded);
+        }
+
+        Ok(ComputerQuestioner {
             host,
             thinking_delay,
-        }
+        })
     }
 }
 
-impl<T: Eq + Hash + Clone> Questioner<T> for ComputerQuestioner<T> {
+impl<T: Eq + Hash + Clone, S: Scorer<T>> ComputerQuestioner<T, S> {
+    pub fn get_thinking_delay(&self) -> u64 {
+        self.thinking_delay
+    }
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> Questioner<T, S::Reply> for ComputerQuestioner<T, S> {
     type Error = HostError<T>;
 
     fn make_new_question(&mut self) {
+        #[cfg(feature = "std")]
         thread::sleep(Duration::from_millis(self.thinking_delay));
 
         let answer_length = self.host.get_answer_length();
 
+        // the host is seeded without `std` (checked by `new`), and the current answer shows that an answer with its length can be drawn
         self.host.renew_with_random_answer(answer_length).unwrap();
     }
 
-    fn answer(&self, answer: &[T]) -> Result<(usize, usize), Self::Error> {
+    fn answer(&self, answer: &[T]) -> Result<S::Reply, Self::Error> {
+        #[cfg(feature = "std")]
         thread::sleep(Duration::from_millis(self.thinking_delay));
         self.host.answer(answer)
     }
@@ -47,40 +69,180 @@ impl<T: Eq + Hash + Clone> Questioner<T> for ComputerQuestioner<T> {
 
 /// A guesser controlled by a computer.
 #[derive(Debug)]
-pub struct ComputerGuesser<T: Eq + Hash + Clone> {
-    letters:                 HashSet<T>,
-    letter_length:           usize,
-    possible_elements_table: Vec<Vec<T>>,
-    guess_times:             usize,
-    thinking_delay:          u64,
+pub struct ComputerGuesser<T: Eq + Hash + Clone, S: Scorer<T> = BullsAndCows> {
+    scorer:                     S,
+    letters:                    Alphabet<T>,
+    letter_lengths:             RangeInclusive<usize>,
+    duplicated_letters_allowed: bool,
+    possible_elements_table:    Vec<Vec<T>>,
+    packed_elements_table:      Vec<PackedCode>,
+    guess_times:                usize,
+    thinking_delay:             u64,
+    rng:                        Option<Box<RefCell<ChaCha8Rng>>>,
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> ComputerGuesser<T, S> {
+    /// The maximum number of possible answers which a computer guesser keeps in memory.
+    pub const MAX_POSSIBLE_ANSWERS: usize = 1 << 24;
 }
 
-impl<T: Eq + Hash + Clone> ComputerGuesser<T> {
-    /// Create a new computer player as a guesser. The `thinking_delay` is a value which simulates the time in milliseconds that a human player needs to take to think.
-    pub fn new(host: &Host<T>, thinking_delay: u64) -> ComputerGuesser<T> {
+impl<T: Eq + Hash + Clone, S: Scorer<T> + Clone> ComputerGuesser<T, S> {
+    /// Create a new computer player as a guesser. The `thinking_delay` is a value which simulates the time in milliseconds that a human player needs to take to think. It only takes effect with the `std` feature. If the length of the answer is hidden, use `new_with_answer_lengths` instead. If there are more than `MAX_POSSIBLE_ANSWERS` possible answers, it returns `HostError::AnswerLengthIncorrect`.
+    pub fn new(
+        host: &Host<T, S>,
+        thinking_delay: u64,
+    ) -> Result<ComputerGuesser<T, S>, HostError<T>> {
+        let answer_length = host.get_answer_length();
+        let expected = Self::get_solvable_length_range(host);
+
+        if !expected.contains(&answer_length) {
+            return Err(HostError::AnswerLengthIncorrect {
+                expected,
+                actual: answer_length,
+            });
+        }
+
+        Ok(Self::new_with_letter_lengths(host, answer_length..=answer_length, thinking_delay))
+    }
+
+    /// Create a new computer player as a guesser which does not know the length of the answer, but only that it is in the range of `answer_lengths`. It finds the length with the answer, so it is for hosts whose scorer hides the length (see `Host::into_hidden_length`). The lengths which cannot be made of the letters, and the longest lengths which would make more than `MAX_POSSIBLE_ANSWERS` possible answers in total, are ignored. If there is no length left, it returns `HostError::AnswerLengthIncorrect`.
+    pub fn new_with_answer_lengths(
+        host: &Host<T, S>,
+        answer_lengths: RangeInclusive<usize>,
+        thinking_delay: u64,
+    ) -> Result<ComputerGuesser<T, S>, HostError<T>> {
+        let expected = Self::get_solvable_length_range(host);
+
+        let start = (*answer_lengths.start()).max(*expected.start());
+        let mut end = (*answer_lengths.end()).min(*expected.end());
+
+        let letters_length = host.get_letters().len();
+        let duplicated_letters_allowed = host.is_duplicated_letters_allowed();
+
+        // every length fits alone, but all of them together may not
+        let mut total = 0usize;
+
+        for letter_length in start..=end {
+            match count_possible_answers(letters_length, letter_length, duplicated_letters_allowed)
+                .and_then(|count| total.checked_add(count))
+                .filter(|&total| total <= Self::MAX_POSSIBLE_ANSWERS)
+            {
+                Some(sum) => total = sum,
+                None => {
+                    end = letter_length - 1;
+
+                    break;
+                },
+            }
+        }
+
+        if start > end {
+            return Err(HostError::AnswerLengthIncorrect {
+                expected,
+                actual: *answer_lengths.end(),
+            });
+        }
+
+        Ok(Self::new_with_letter_lengths(host, start..=end, thinking_delay))
+    }
+
+    /// The range of the lengths of answers which the host accepts and which do not make more than `MAX_POSSIBLE_ANSWERS` possible answers.
+    fn get_solvable_length_range(host: &Host<T, S>) -> RangeInclusive<usize> {
+        let expected = host.get_answer_length_range();
+        let letters_length = host.get_letters().len();
+        let duplicated_letters_allowed = host.is_duplicated_letters_allowed();
+
+        // the number of possible answers grows with the length
+        let end = expected
+            .clone()
+            .take_while(|&letter_length| {
+                count_possible_answers(letters_length, letter_length, duplicated_letters_allowed)
+                    .is_some_and(|count| count <= Self::MAX_POSSIBLE_ANSWERS)
+            })
+            .last()
+            .unwrap_or(0);
+
+        *expected.start()..=end
+    }
+
+    fn new_with_letter_lengths(
+        host: &Host<T, S>,
+        letter_lengths: RangeInclusive<usize>,
+        thinking_delay: u64,
+    ) -> ComputerGuesser<T, S> {
+        let scorer = host.get_scorer().clone();
         let letters = host.get_letters().clone();
-        let letter_length = host.get_answer_length();
-        let possible_elements_table =
-            ComputerGuesser::make_possible_elements_table(&letters, letter_length);
+        let duplicated_letters_allowed = host.is_duplicated_letters_allowed();
+        let possible_elements_table = Self::make_possible_elements_table_for_lengths(
+            &letters,
+            letter_lengths.clone(),
+            duplicated_letters_allowed,
+        );
+        let packed_elements_table =
+            Self::make_packed_elements_table(&letters, &possible_elements_table);
 
         ComputerGuesser {
+            scorer,
             letters,
-            letter_length,
+            letter_lengths,
+            duplicated_letters_allowed,
             possible_elements_table,
+            packed_elements_table,
             guess_times: 0,
             thinking_delay,
+            rng: None,
         }
     }
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> ComputerGuesser<T, S> {
+    pub fn get_thinking_delay(&self) -> u64 {
+        self.thinking_delay
+    }
 
-    fn make_possible_elements_table(letters: &HashSet<T>, letter_length: usize) -> Vec<Vec<T>> {
-        let letters_length = letters.len();
+    /// Seed the random number generator used for picking guesses. Without the `std` feature there is no source of entropy, so an unseeded guesser returns `HostError::RngUnseeded` when it guesses.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = Some(Box::new(RefCell::new(ChaCha8Rng::seed_from_u64(seed))));
+    }
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> ComputerGuesser<T, S> {
+    fn make_possible_elements_table_for_lengths(
+        letters: &Alphabet<T>,
+        letter_lengths: RangeInclusive<usize>,
+        duplicated_letters_allowed: bool,
+    ) -> Vec<Vec<T>> {
+        let mut possible_elements_table = Vec::new();
+
+        for letter_length in letter_lengths {
+            possible_elements_table.append(&mut Self::make_possible_elements_table(
+                letters,
+                letter_length,
+                duplicated_letters_allowed,
+            ));
+        }
 
-        let mut capacity = 1;
+        possible_elements_table
+    }
 
-        for _ in 0..letter_length {
-            capacity *= letters_length - letter_length;
+    fn make_possible_elements_table(
+        letters: &Alphabet<T>,
+        letter_length: usize,
+        duplicated_letters_allowed: bool,
+    ) -> Vec<Vec<T>> {
+        if duplicated_letters_allowed {
+            return Self::make_possible_elements_table_with_duplicated_letters(
+                letters,
+                letter_length,
+            );
         }
 
+        let letters_length = letters.len();
+
+        let capacity = count_possible_answers(letters_length, letter_length, false)
+            .unwrap_or_default()
+            .min(Self::MAX_POSSIBLE_ANSWERS);
+
         let mut possible_elements_table = Vec::with_capacity(capacity);
 
         let letters_vec: Vec<&T> = letters.iter().collect();
@@ -98,9 +260,9 @@ impl<T: Eq + Hash + Clone> ComputerGuesser<T> {
                 a.push(letters_vec[offset_array[i]]);
             }
 
-            let heap = Heap::new(&mut a);
+            let mut heap = Heap::new(&mut a);
 
-            for a in heap {
+            while let Some(a) = heap.next_permutation() {
                 possible_elements_table.push(a.iter().map(|&e| e.clone()).collect());
             }
 
@@ -138,9 +300,97 @@ impl<T: Eq + Hash + Clone> ComputerGuesser<T> {
 
         possible_elements_table
     }
+
+    /// Pack every possible answer. If any of them cannot be packed, the table is empty.
+    fn make_packed_elements_table(
+        letters: &Alphabet<T>,
+        possible_elements_table: &[Vec<T>],
+    ) -> Vec<PackedCode> {
+        possible_elements_table
+            .iter()
+            .map(|answer| PackedCode::new(letters, answer))
+            .collect::<Option<Vec<PackedCode>>>()
+            .unwrap_or_default()
+    }
+
+    fn make_possible_elements_table_with_duplicated_letters(
+        letters: &Alphabet<T>,
+        letter_length: usize,
+    ) -> Vec<Vec<T>> {
+        let letters_length = letters.len();
+
+        let capacity = count_possible_answers(letters_length, letter_length, true)
+            .unwrap_or_default()
+            .min(Self::MAX_POSSIBLE_ANSWERS);
+
+        let mut possible_elements_table = Vec::with_capacity(capacity);
+
+        let letters_vec: Vec<&T> = letters.iter().collect();
+
+        let mut offset_array: Vec<usize> = vec![0; letter_length];
+
+        loop {
+            possible_elements_table
+                .push(offset_array.iter().map(|&i| letters_vec[i].clone()).collect());
+
+            let mut end = letter_length;
+
+            loop {
+                if end == 0 {
+                    return possible_elements_table;
+                }
+
+                end -= 1;
+
+                offset_array[end] += 1;
+
+                if offset_array[end] < letters_length {
+                    break;
+                }
+
+                offset_array[end] = 0;
+            }
+        }
+    }
 }
 
-impl<T: Eq + Hash + Clone> Guesser<T> for ComputerGuesser<T> {
+impl<T: Eq + Hash + Clone, S: Scorer<T>> ComputerGuesser<T, S> {
+    /// The expected number of possible answers which are left after the guess is replied.
+    fn expected_remaining(&self, guess: &[T]) -> f64 {
+        let packed_elements_table = if self.packed_elements_table.is_empty() {
+            None
+        } else {
+            Some(self.packed_elements_table.as_slice())
+        };
+
+        let mut replies = Vec::with_capacity(self.possible_elements_table.len());
+
+        scorer::score_candidates(
+            &self.scorer,
+            &self.letters,
+            &self.possible_elements_table,
+            packed_elements_table,
+            guess,
+            &mut replies,
+        );
+
+        // replies are only comparable, so they are grouped linearly
+        let mut groups: Vec<(S::Reply, usize)> = Vec::new();
+
+        for reply in replies {
+            match groups.iter_mut().find(|(r, _)| *r == reply) {
+                Some((_, count)) => *count += 1,
+                None => groups.push((reply, 1)),
+            }
+        }
+
+        let sum: usize = groups.iter().map(|(_, count)| count * count).sum();
+
+        sum as f64 / self.possible_elements_table.len() as f64
+    }
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> Guesser<T, S::Reply> for ComputerGuesser<T, S> {
     type Error = HostError<T>;
 
     fn get_guess_times(&self) -> usize {
@@ -151,41 +401,228 @@ impl<T: Eq + Hash + Clone> Guesser<T> for ComputerGuesser<T> {
         self.guess_times = guess_times
     }
 
-    fn add_condition(&mut self, guess: &[T], reply: (usize, usize)) {
+    fn add_condition(&mut self, guess: &[T], reply: S::Reply) {
+        #[cfg(feature = "std")]
         let now = Instant::now();
 
-        let host =
-            unsafe { Host::build_with_known_answer_unsafe(self.letters.clone(), guess.to_vec()) };
+        let packed_elements_table = if self.packed_elements_table.is_empty() {
+            None
+        } else {
+            Some(self.packed_elements_table.as_slice())
+        };
 
-        for i in (0..(self.possible_elements_table.len())).rev() {
-            let re = {
-                let answer = &self.possible_elements_table[i];
+        let mut replies = Vec::with_capacity(self.possible_elements_table.len());
 
-                host.answer(answer).unwrap()
-            };
+        scorer::score_candidates(
+            &self.scorer,
+            &self.letters,
+            &self.possible_elements_table,
+            packed_elements_table,
+            guess,
+            &mut replies,
+        );
 
-            if re != reply {
-                self.possible_elements_table.remove(i);
-            }
+        let keep: Vec<bool> = replies.into_iter().map(|r| r == reply).collect();
+
+        let mut flags = keep.iter();
+        self.possible_elements_table.retain(|_| *flags.next().unwrap());
+
+        if !self.packed_elements_table.is_empty() {
+            let mut flags = keep.iter();
+            self.packed_elements_table.retain(|_| *flags.next().unwrap());
         }
 
         if self.possible_elements_table.is_empty() {
-            self.possible_elements_table =
-                ComputerGuesser::make_possible_elements_table(&self.letters, self.letter_length);
+            self.possible_elements_table = Self::make_possible_elements_table_for_lengths(
+                &self.letters,
+                self.letter_lengths.clone(),
+                self.duplicated_letters_allowed,
+            );
+            self.packed_elements_table =
+                Self::make_packed_elements_table(&self.letters, &self.possible_elements_table);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let dt = now.elapsed().as_millis();
+
+            if dt < u128::from(self.thinking_delay) {
+                thread::sleep(Duration::from_millis(self.thinking_delay - dt as u64));
+            }
+        }
+    }
+
+    fn guess(&self) -> Result<Vec<T>, Self::Error> {
+        #[cfg(feature = "std")]
+        thread::sleep(Duration::from_millis(self.thinking_delay));
+
+        let picked = match self.rng.as_ref() {
+            Some(rng) => self.possible_elements_table.choose(&mut *rng.borrow_mut()),
+            #[cfg(feature = "std")]
+            None => random_pick::pick_from_slice(&self.possible_elements_table, &[1]),
+            #[cfg(not(feature = "std"))]
+            None => return Err(HostError::RngUnseeded),
+        };
+
+        Ok(picked.unwrap().clone())
+    }
+}
+
+/// The number of answers with the length which can be made of the letters, or `None` if it overflows.
+fn count_possible_answers(
+    letters_length: usize,
+    letter_length: usize,
+    duplicated_letters_allowed: bool,
+) -> Option<usize> {
+    if duplicated_letters_allowed {
+        letters_length.checked_pow(u32::try_from(letter_length).ok()?)
+    } else if letter_length > letters_length {
+        Some(0)
+    } else {
+        (letters_length - letter_length + 1..=letters_length)
+            .try_fold(1usize, |count, n| count.checked_mul(n))
+    }
+}
+
+/// The most possible answers which `MultiComputerGuesser` considers for a guess.
+const MULTI_GUESS_CANDIDATES: usize = 32;
+
+/// A guesser controlled by a computer for a host with several answers (see `MultiHost`). It keeps the possible answers for every unsolved answer, and guesses an answer which is certain if there is one, or else the one among some possible answers of the answer which has the fewest of them that leaves the fewest possible answers for all of the unsolved answers together.
+#[derive(Debug)]
+pub struct MultiComputerGuesser<T: Eq + Hash + Clone, S: Scorer<T> = BullsAndCows> {
+    guessers:       Vec<ComputerGuesser<T, S>>,
+    solved:         Vec<bool>,
+    guess_times:    usize,
+    thinking_delay: u64,
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T> + Clone> MultiComputerGuesser<T, S> {
+    /// Create a new computer player as a guesser for all of the answers of the host. The `thinking_delay` is a value which simulates the time in milliseconds that a human player needs to take to think. It only takes effect with the `std` feature. If there are more than `ComputerGuesser::MAX_POSSIBLE_ANSWERS` possible answers, it returns `HostError::AnswerLengthIncorrect`.
+    pub fn new(
+        host: &MultiHost<T, S>,
+        thinking_delay: u64,
+    ) -> Result<MultiComputerGuesser<T, S>, HostError<T>> {
+        let guessers = host
+            .get_hosts()
+            .iter()
+            .map(|host| ComputerGuesser::new(host, 0))
+            .collect::<Result<Vec<ComputerGuesser<T, S>>, HostError<T>>>()?;
+        let solved = vec![false; guessers.len()];
+
+        Ok(MultiComputerGuesser {
+            guessers,
+            solved,
+            guess_times: 0,
+            thinking_delay,
+        })
+    }
+
+    /// Create a new computer player as a guesser for all of the answers of the host, which does not know the lengths of the answers, but only that they are in the range of `answer_lengths` (see `ComputerGuesser::new_with_answer_lengths`).
+    pub fn new_with_answer_lengths(
+        host: &MultiHost<T, S>,
+        answer_lengths: RangeInclusive<usize>,
+        thinking_delay: u64,
+    ) -> Result<MultiComputerGuesser<T, S>, HostError<T>> {
+        let guessers = host
+            .get_hosts()
+            .iter()
+            .map(|host| ComputerGuesser::new_with_answer_lengths(host, answer_lengths.clone(), 0))
+            .collect::<Result<Vec<ComputerGuesser<T, S>>, HostError<T>>>()?;
+        let solved = vec![false; guessers.len()];
+
+        Ok(MultiComputerGuesser {
+            guessers,
+            solved,
+            guess_times: 0,
+            thinking_delay,
+        })
+    }
+}
+
+impl<T: Eq + Hash + Clone, S: Scorer<T>> MultiComputerGuesser<T, S> {
+    pub fn get_thinking_delay(&self) -> u64 {
+        self.thinking_delay
+    }
+
+    /// Whether every answer has been guessed according to the added conditions.
+    pub fn get_solved(&self) -> &[bool] {
+        &self.solved
+    }
+
+    /// Seed the random number generators used for picking guesses. Without the `std` feature there is no source of entropy, so an unseeded guesser returns `HostError::RngUnseeded` when it guesses.
+    pub fn set_seed(&mut self, seed: u64) {
+        for (i, guesser) in self.guessers.iter_mut().enumerate() {
+            guesser.set_seed(seed.wrapping_add(i as u64));
         }
+    }
+}
 
-        let dt = now.elapsed().as_millis();
+impl<T: Eq + Hash + Clone, S: Scorer<T>> Guesser<T, Vec<S::Reply>> for MultiComputerGuesser<T, S> {
+    type Error = HostError<T>;
 
-        if dt < u128::from(self.thinking_delay) {
-            thread::sleep(Duration::from_millis(self.thinking_delay - dt as u64));
+    fn get_guess_times(&self) -> usize {
+        self.guess_times
+    }
+
+    fn set_guess_times(&mut self, guess_times: usize) {
+        self.guess_times = guess_times
+    }
+
+    fn add_condition(&mut self, guess: &[T], replies: Vec<S::Reply>) {
+        for ((guesser, solved), reply) in
+            self.guessers.iter_mut().zip(self.solved.iter_mut()).zip(replies)
+        {
+            if *solved {
+                continue;
+            }
+
+            // a winning guess is exactly the answer, so the length of the answer is the length of the guess
+            if guesser.scorer.is_win(&reply, guess.len()) {
+                *solved = true;
+            } else {
+                guesser.add_condition(guess, reply);
+            }
         }
     }
 
     fn guess(&self) -> Result<Vec<T>, Self::Error> {
+        #[cfg(feature = "std")]
         thread::sleep(Duration::from_millis(self.thinking_delay));
 
-        let picked = random_pick::pick_from_slice(&self.possible_elements_table, &[1]).unwrap();
+        let unsolved: Vec<&ComputerGuesser<T, S>> = self
+            .guessers
+            .iter()
+            .zip(self.solved.iter())
+            .filter(|(_, &solved)| !solved)
+            .map(|(guesser, _)| guesser)
+            .collect();
+
+        // the answer with the fewest possible answers is the closest to be solved, and it is certain if there is only one
+        let guesser = unsolved
+            .iter()
+            .min_by_key(|guesser| guesser.possible_elements_table.len())
+            .ok_or(HostError::AnswersSolved)?;
+
+        let picked = guesser.guess()?;
+
+        if guesser.possible_elements_table.len() == 1 || unsolved.len() == 1 {
+            return Ok(picked);
+        }
+
+        // among some of its possible answers, pick the one which leaves the fewest possible answers for all of the unsolved answers together
+        let step = guesser.possible_elements_table.len().div_ceil(MULTI_GUESS_CANDIDATES);
+
+        let best = iter::once(&picked)
+            .chain(guesser.possible_elements_table.iter().step_by(step))
+            .map(|candidate| {
+                let remaining: f64 =
+                    unsolved.iter().map(|guesser| guesser.expected_remaining(candidate)).sum();
+
+                (candidate, remaining)
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
 
-        Ok(picked.clone())
+        Ok(best.0.clone())
     }
 }
@@ -1,18 +1,21 @@
-use std::hash::Hash;
+use alloc::vec::Vec;
+use core::hash::Hash;
 
-/// A player who asks questions in the game.
-pub trait Questioner<T: Eq + Hash + Clone> {
+use crate::Score;
+
+/// A player who asks questions in the game. The reply for a guess is `R`, which is `Score` by default.
+pub trait Questioner<T: Eq + Hash + Clone, R = Score> {
     type Error;
 
     /// Make a new question.
     fn make_new_question(&mut self);
 
     /// Answer for the question.
-    fn answer(&self, answer: &[T]) -> Result<(usize, usize), Self::Error>;
+    fn answer(&self, answer: &[T]) -> Result<R, Self::Error>;
 }
 
-/// A player who make guesses in the game.
-pub trait Guesser<T: Eq + Hash + Clone> {
+/// A player who make guesses in the game. The reply for a guess is `R`, which is `Score` by default.
+pub trait Guesser<T: Eq + Hash + Clone, R = Score> {
     type Error;
 
     /// Get guess times of this player.
@@ -22,7 +25,7 @@ pub trait Guesser<T: Eq + Hash + Clone> {
     fn set_guess_times(&mut self, guess_times: usize);
 
     /// Add a condition.
-    fn add_condition(&mut self, guess: &[T], reply: (usize, usize));
+    fn add_condition(&mut self, guess: &[T], reply: R);
 
     /// Make a guess.
     fn guess(&self) -> Result<Vec<T>, Self::Error>;
@@ -0,0 +1,3 @@
+/// A wrapper whose `Debug` shows the answer of a host (or a validated answer), which is hidden by the `Debug` of the host itself. It is created by the `reveal` methods.
+#[derive(Clone, Copy)]
+pub struct Revealed<'a, H: ?Sized>(pub(crate) &'a H);
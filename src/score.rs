@@ -0,0 +1,113 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+#[cfg(feature = "std")]
+use std::sync::LazyLock;
+
+use crate::parser::{ABParser, ParseError};
+
+#[cfg(feature = "std")]
+static AB_PARSER: LazyLock<ABParser> = LazyLock::new(ABParser::new);
+
+/// The reply for a guess, which is the number of bulls (`A`) and the number of cows (`B`). Scores are ordered by bulls first and then by cows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Score {
+    /// The number of letters which are in the answer and in the correct position.
+    pub bulls: usize,
+    /// The number of letters which are in the answer but in a wrong position.
+    pub cows:  usize,
+}
+
+impl Score {
+    /// Create a new `Score` instance.
+    #[inline]
+    pub const fn new(bulls: usize, cows: usize) -> Score {
+        Score {
+            bulls,
+            cows,
+        }
+    }
+}
+
+impl Score {
+    /// Count the bulls and the cows in a per-position feedback.
+    pub fn from_feedback(feedback: &[Feedback]) -> Score {
+        let mut score = Score::default();
+
+        for f in feedback {
+            match f {
+                Feedback::Bull => score.bulls += 1,
+                Feedback::Cow => score.cows += 1,
+                Feedback::Miss => (),
+            }
+        }
+
+        score
+    }
+
+    /// Whether this score means the guess is the answer.
+    #[inline]
+    pub const fn is_win(&self, answer_length: usize) -> bool {
+        self.bulls == answer_length && self.cows == 0
+    }
+
+    /// Whether this score is possible for an answer with the length of `answer_length`. The sum of bulls and cows cannot exceed the length, and `(n-1)A1B` can never happen because the only misplaced letter would have to be in the only remaining position.
+    pub fn is_valid(&self, answer_length: usize) -> bool {
+        match self.bulls.checked_add(self.cows) {
+            Some(sum) => {
+                sum <= answer_length && !(self.cows == 1 && self.bulls + 1 == answer_length)
+            },
+            None => false,
+        }
+    }
+}
+
+impl Display for Score {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_fmt(format_args!("{}A{}B", self.bulls, self.cows))
+    }
+}
+
+impl FromStr for Score {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "std")]
+        {
+            AB_PARSER.parse(s)
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            ABParser::new().parse(s)
+        }
+    }
+}
+
+impl From<(usize, usize)> for Score {
+    #[inline]
+    fn from((bulls, cows): (usize, usize)) -> Self {
+        Score::new(bulls, cows)
+    }
+}
+
+impl From<Score> for (usize, usize) {
+    #[inline]
+    fn from(score: Score) -> Self {
+        (score.bulls, score.cows)
+    }
+}
+
+/// The classification of a letter at a position of a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feedback {
+    /// The letter is in the answer and in the correct position.
+    Bull,
+    /// The letter is in the answer but in a wrong position.
+    Cow,
+    /// The letter is not in the answer, or all of its occurrences in the answer have already been matched.
+    Miss,
+}
@@ -0,0 +1,499 @@
+/*!
+Rules for replying to guesses.
+
+A `Scorer` decides what the reply for a guess is. `Host` and `ComputerGuesser` are generic over it, so the same host and solver can be used for many variants of Bulls and Cows.
+*/
+
+use alloc::{vec, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+use crate::{Alphabet, Feedback, Score};
+
+/// A rule for replying to guesses.
+pub trait Scorer<T> {
+    /// The reply for a guess.
+    type Reply: Debug + Clone + Eq;
+
+    /// Reply to the guess. The guess is assumed to be valid for the answer, which means they have the same length and contain only valid letters.
+    fn score(&self, answer: &[T], guess: &[T]) -> Self::Reply;
+
+    /// Whether the reply means the guess is the answer.
+    fn is_win(&self, reply: &Self::Reply, answer_length: usize) -> bool;
+
+    /// Reply to the guess by using the packed forms of the answer and the guess, which have the same length. It must be the same as the reply of `score`. Return `None` if the rule cannot be decided from the packed forms, which is the default.
+    #[inline]
+    fn score_packed(&self, _answer: &PackedCode, _guess: &PackedCode) -> Option<Self::Reply> {
+        None
+    }
+
+    /// Whether guesses of any length are allowed, so that the guesser has to find the length of the answer too. If so, `score` must handle a guess whose length is not the length of the answer. It is `false` by default.
+    #[inline]
+    fn is_length_hidden(&self) -> bool {
+        false
+    }
+}
+
+/// The classic rule. The reply is the number of bulls and the number of cows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BullsAndCows;
+
+impl<T: Eq> Scorer<T> for BullsAndCows {
+    type Reply = Score;
+
+    #[inline]
+    fn score(&self, answer: &[T], guess: &[T]) -> Score {
+        Score::from_feedback(&make_feedback(answer, guess))
+    }
+
+    #[inline]
+    fn is_win(&self, reply: &Score, answer_length: usize) -> bool {
+        reply.is_win(answer_length)
+    }
+
+    #[inline]
+    fn score_packed(&self, answer: &PackedCode, guess: &PackedCode) -> Option<Score> {
+        let bulls = answer.bulls(guess);
+
+        Some(Score::new(bulls, answer.common(guess) - bulls))
+    }
+}
+
+/// The reply is only the number of bulls (the black pegs in Mastermind).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BullsOnly;
+
+impl<T: Eq> Scorer<T> for BullsOnly {
+    type Reply = usize;
+
+    #[inline]
+    fn score(&self, answer: &[T], guess: &[T]) -> usize {
+        answer.iter().zip(guess.iter()).filter(|(a, g)| a.eq(g)).count()
+    }
+
+    #[inline]
+    fn is_win(&self, reply: &usize, answer_length: usize) -> bool {
+        *reply == answer_length
+    }
+
+    #[inline]
+    fn score_packed(&self, answer: &PackedCode, guess: &PackedCode) -> Option<usize> {
+        Some(answer.bulls(guess))
+    }
+}
+
+/// The reply is only the sum of bulls and cows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TotalMatches;
+
+impl<T: Eq> Scorer<T> for TotalMatches {
+    type Reply = Matches;
+
+    #[inline]
+    fn score(&self, answer: &[T], guess: &[T]) -> Matches {
+        let score = Score::from_feedback(&make_feedback(answer, guess));
+
+        Matches {
+            count: score.bulls + score.cows, exact: answer == guess
+        }
+    }
+
+    #[inline]
+    fn is_win(&self, reply: &Matches, _answer_length: usize) -> bool {
+        reply.exact
+    }
+
+    #[inline]
+    fn score_packed(&self, answer: &PackedCode, guess: &PackedCode) -> Option<Matches> {
+        Some(Matches {
+            count: answer.common(guess), exact: answer == guess
+        })
+    }
+}
+
+/// The rule of Jotto. The reply is the number of distinct letters which the guess and the answer have in common, regardless of their positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Jotto;
+
+impl<T: Eq> Scorer<T> for Jotto {
+    type Reply = Matches;
+
+    #[inline]
+    fn score(&self, answer: &[T], guess: &[T]) -> Matches {
+        let count = guess
+            .iter()
+            .enumerate()
+            .filter(|&(i, letter)| !guess[..i].contains(letter) && answer.contains(letter))
+            .count();
+
+        Matches {
+            count,
+            exact: answer == guess,
+        }
+    }
+
+    #[inline]
+    fn is_win(&self, reply: &Matches, _answer_length: usize) -> bool {
+        reply.exact
+    }
+
+    #[inline]
+    fn score_packed(&self, answer: &PackedCode, guess: &PackedCode) -> Option<Matches> {
+        Some(Matches {
+            count: answer.common(guess), exact: answer == guess
+        })
+    }
+}
+
+/// The rule of Bagels. The numbers are the same as the classic rule, but the reply is worded with `Fermi` (a bull), `Pico` (a cow) and `Bagels` (nothing).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bagels;
+
+impl<T: Eq> Scorer<T> for Bagels {
+    type Reply = Clues;
+
+    #[inline]
+    fn score(&self, answer: &[T], guess: &[T]) -> Clues {
+        let score = Score::from_feedback(&make_feedback(answer, guess));
+
+        Clues {
+            fermi: score.bulls, pico: score.cows
+        }
+    }
+
+    #[inline]
+    fn is_win(&self, reply: &Clues, answer_length: usize) -> bool {
+        reply.fermi == answer_length
+    }
+
+    #[inline]
+    fn score_packed(&self, answer: &PackedCode, guess: &PackedCode) -> Option<Clues> {
+        let bulls = answer.bulls(guess);
+
+        Some(Clues {
+            fermi: bulls, pico: answer.common(guess) - bulls
+        })
+    }
+}
+
+/// A reply which only tells how many letters are matched and whether the guess is exactly the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Matches {
+    /// The number of matched letters.
+    pub count: usize,
+    /// Whether the guess is exactly the answer.
+    pub exact: bool,
+}
+
+impl Display for Matches {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        Display::fmt(&self.count, f)
+    }
+}
+
+/// A reply of the Bagels rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Clues {
+    /// The number of letters which are in the answer and in the correct position.
+    pub fermi: usize,
+    /// The number of letters which are in the answer but in a wrong position.
+    pub pico:  usize,
+}
+
+impl Display for Clues {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        if self.fermi == 0 && self.pico == 0 {
+            return f.write_str("Bagels");
+        }
+
+        let mut separator = "";
+
+        for _ in 0..self.fermi {
+            f.write_str(separator)?;
+            f.write_str("Fermi")?;
+
+            separator = " ";
+        }
+
+        for _ in 0..self.pico {
+            f.write_str(separator)?;
+            f.write_str("Pico")?;
+
+            separator = " ";
+        }
+
+        Ok(())
+    }
+}
+
+/// The hidden-length variant of another rule. Guesses of any length are allowed, and the reply of the inner rule comes with whether the guess is too short or too long.
+///
+/// The inner rule scores the whole guess against the whole answer. For all the rules in this module, letters are only compared position by position where both the guess and the answer have a letter, and letters are matched regardless of their positions across the whole guess and the whole answer. For example, with the classic rule, the guess `[1, 2]` for the answer `[1, 3, 2]` has 1 bull and 1 cow and is too short.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HiddenLength<S = BullsAndCows> {
+    scorer: S,
+}
+
+impl<S> HiddenLength<S> {
+    #[inline]
+    pub const fn new(scorer: S) -> HiddenLength<S> {
+        HiddenLength {
+            scorer,
+        }
+    }
+
+    #[inline]
+    pub const fn get_scorer(&self) -> &S {
+        &self.scorer
+    }
+}
+
+impl<T, S: Scorer<T>> Scorer<T> for HiddenLength<S> {
+    type Reply = HiddenLengthReply<S::Reply>;
+
+    #[inline]
+    fn score(&self, answer: &[T], guess: &[T]) -> Self::Reply {
+        HiddenLengthReply {
+            reply:  self.scorer.score(answer, guess),
+            length: LengthHint::compare(answer.len(), guess.len()),
+        }
+    }
+
+    #[inline]
+    fn is_win(&self, reply: &Self::Reply, answer_length: usize) -> bool {
+        reply.length == LengthHint::Exact && self.scorer.is_win(&reply.reply, answer_length)
+    }
+
+    #[inline]
+    fn score_packed(&self, answer: &PackedCode, guess: &PackedCode) -> Option<Self::Reply> {
+        if answer.len() != guess.len() {
+            return None;
+        }
+
+        self.scorer.score_packed(answer, guess).map(|reply| HiddenLengthReply {
+            reply,
+            length: LengthHint::Exact,
+        })
+    }
+
+    #[inline]
+    fn is_length_hidden(&self) -> bool {
+        true
+    }
+}
+
+/// Whether a guess is shorter than, as long as, or longer than the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthHint {
+    TooShort,
+    Exact,
+    TooLong,
+}
+
+impl LengthHint {
+    /// Compare the length of a guess with the length of the answer.
+    #[inline]
+    pub fn compare(answer_length: usize, guess_length: usize) -> LengthHint {
+        match guess_length.cmp(&answer_length) {
+            Ordering::Less => LengthHint::TooShort,
+            Ordering::Equal => LengthHint::Exact,
+            Ordering::Greater => LengthHint::TooLong,
+        }
+    }
+}
+
+/// A reply of the hidden-length variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HiddenLengthReply<R> {
+    /// The reply of the inner rule.
+    pub reply:  R,
+    /// Whether the guess is too short or too long.
+    pub length: LengthHint,
+}
+
+impl<R: Display> Display for HiddenLengthReply<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        Display::fmt(&self.reply, f)?;
+
+        match self.length {
+            LengthHint::TooShort => f.write_str(" (too short)"),
+            LengthHint::Exact => Ok(()),
+            LengthHint::TooLong => f.write_str(" (too long)"),
+        }
+    }
+}
+
+/// A code (an answer or a guess) without duplicated letters packed into integers, for alphabets with at most 64 letters and codes with at most 16 letters. The letters of the code are a bitset of the indices of the letters in the alphabet, and the positions are the indices packed into bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedCode {
+    letters:   u64,
+    positions: u128,
+    len:       u8,
+}
+
+/// `0x7F` in every byte.
+const LOW_SEVEN_BITS: u128 = u128::MAX / 0xFF * 0x7F;
+
+impl PackedCode {
+    /// The maximum length of a code.
+    pub const MAX_LENGTH: usize = 16;
+    /// The maximum number of letters in an alphabet.
+    pub const MAX_LETTERS: usize = 64;
+
+    /// Pack a code. If the alphabet or the code is too long, the code contains a letter which is not in the alphabet, or the code contains duplicated letters, it returns `None`.
+    pub fn new<T: Eq>(alphabet: &Alphabet<T>, code: &[T]) -> Option<PackedCode> {
+        if alphabet.len() > Self::MAX_LETTERS || code.len() > Self::MAX_LENGTH {
+            return None;
+        }
+
+        let mut letters = 0u64;
+        let mut positions = 0u128;
+
+        for (i, letter) in code.iter().enumerate() {
+            let index = alphabet.index_of(letter)?;
+            let bit = 1 << index;
+
+            if letters & bit != 0 {
+                return None;
+            }
+
+            letters |= bit;
+            positions |= (index as u128) << (i * 8);
+        }
+
+        Some(PackedCode {
+            letters,
+            positions,
+            len: code.len() as u8,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn zeroize(&mut self) {
+        use zeroize::Zeroize;
+
+        self.letters.zeroize();
+        self.positions.zeroize();
+        self.len.zeroize();
+    }
+
+    /// The number of positions which have the same letter in both codes. Both codes must have the same length.
+    #[inline]
+    pub fn bulls(&self, other: &PackedCode) -> usize {
+        let x = self.positions ^ other.positions;
+
+        // the highest bit of a byte is set if and only if the byte is zero
+        let zero_bytes = !(((x & LOW_SEVEN_BITS) + LOW_SEVEN_BITS) | x | LOW_SEVEN_BITS);
+
+        // the unused bytes are zero in both codes
+        zero_bytes.count_ones() as usize - (Self::MAX_LENGTH - self.len())
+    }
+
+    /// The number of letters which are in both codes, regardless of their positions.
+    #[inline]
+    pub fn common(&self, other: &PackedCode) -> usize {
+        (self.letters & other.letters).count_ones() as usize
+    }
+}
+
+/// Reply to the guess for every candidate answer, appending the replies to `replies` in order. `packed_candidates` can be the packed forms of all candidates, or they are packed on the fly. The packed forms are used only if all of the candidates, the guess and the scorer support them.
+pub(crate) fn score_candidates<T: Eq, S: Scorer<T>, C: AsRef<[T]>>(
+    scorer: &S,
+    letters: &Alphabet<T>,
+    candidates: &[C],
+    packed_candidates: Option<&[PackedCode]>,
+    guess: &[T],
+    replies: &mut Vec<S::Reply>,
+) {
+    let start = replies.len();
+
+    if let Some(packed_guess) = PackedCode::new(letters, guess) {
+        let packed = |i: usize, candidate: &C| match packed_candidates {
+            Some(packed_candidates) => Some(packed_candidates[i]),
+            None => PackedCode::new(letters, candidate.as_ref()),
+        };
+
+        let mut completed = true;
+
+        for (i, candidate) in candidates.iter().enumerate() {
+            let reply = match packed(i, candidate) {
+                // codes with different lengths are only compared when the length is hidden, and packed codes cannot be compared then
+                Some(packed_candidate) if packed_candidate.len() != packed_guess.len() => {
+                    Some(scorer.score(candidate.as_ref(), guess))
+                },
+                Some(packed_candidate) => scorer.score_packed(&packed_candidate, &packed_guess),
+                None => None,
+            };
+
+            match reply {
+                Some(reply) => replies.push(reply),
+                None => {
+                    completed = false;
+
+                    break;
+                },
+            }
+        }
+
+        if completed {
+            return;
+        }
+
+        replies.truncate(start);
+    }
+
+    replies.extend(candidates.iter().map(|candidate| scorer.score(candidate.as_ref(), guess)));
+}
+
+/// Classify the letter at every position of the guess. When a letter appears more times in the guess than in the answer, the leftmost occurrences are the cows.
+pub(crate) fn make_feedback<T: Eq>(answer: &[T], guess: &[T]) -> Vec<Feedback> {
+    let mut feedback = vec![Feedback::Miss; guess.len()];
+    let mut used = vec![false; answer.len()];
+
+    fill_feedback(answer, guess, &mut feedback, &mut used);
+
+    feedback
+}
+
+/// Classify the letter at every position of the guess into `feedback`, without allocating. `used` is a scratch buffer as long as the answer.
+pub(crate) fn fill_feedback<T: Eq>(
+    answer: &[T],
+    guess: &[T],
+    feedback: &mut [Feedback],
+    used: &mut [bool],
+) {
+    for (i, (a, b)) in answer.iter().zip(guess.iter()).enumerate() {
+        let bull = a.eq(b);
+
+        feedback[i] = if bull { Feedback::Bull } else { Feedback::Miss };
+
+        // every letter in the answer can only be matched once, so a letter appearing several times is not counted repeatedly
+        used[i] = bull;
+    }
+
+    for (i, letter) in guess.iter().enumerate() {
+        if feedback[i] == Feedback::Bull {
+            continue;
+        }
+
+        let position = answer.iter().zip(used.iter()).position(|(e, &u)| !u && e.eq(letter));
+
+        if let Some(j) = position {
+            used[j] = true;
+            feedback[i] = Feedback::Cow;
+        }
+    }
+}
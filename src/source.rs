@@ -0,0 +1,202 @@
+/*!
+Sources of secret answers.
+
+A `SecretSource` decides how `Host::renew_with_source` picks the next answer. `Uniform` draws every answer with the same probability, `WeightedList` draws answers from a list in proportion to their weights (for example, easier numbers or more common words more often), and `WithoutReplacement` draws every answer from a pool once before any of them repeats.
+*/
+
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use rand::Rng;
+
+use crate::{scorer::Scorer, Host, HostError};
+
+/// A way of picking answers for a host.
+pub trait SecretSource<T: Eq + Hash + Clone> {
+    /// Draw an answer which the host accepts. If there is no such answer, it returns an error.
+    fn draw<S: Scorer<T>, R: Rng + ?Sized>(
+        &mut self,
+        host: &Host<T, S>,
+        rng: &mut R,
+    ) -> Result<Vec<T>, HostError<T>>;
+}
+
+/// Draw every answer with the given length which the host accepts with the same probability. It is what `Host::renew_with_random_answer` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Uniform {
+    answer_length: usize,
+}
+
+impl Uniform {
+    #[inline]
+    pub const fn new(answer_length: usize) -> Uniform {
+        Uniform {
+            answer_length,
+        }
+    }
+
+    #[inline]
+    pub const fn get_answer_length(&self) -> usize {
+        self.answer_length
+    }
+}
+
+impl<T: Eq + Hash + Clone> SecretSource<T> for Uniform {
+    #[inline]
+    fn draw<S: Scorer<T>, R: Rng + ?Sized>(
+        &mut self,
+        host: &Host<T, S>,
+        rng: &mut R,
+    ) -> Result<Vec<T>, HostError<T>> {
+        host.random_answer(self.answer_length, rng)
+    }
+}
+
+/// Draw answers from a list in proportion to their weights. The answers which the host does not accept are skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WeightedList<T> {
+    entries: Vec<(Vec<T>, u32)>,
+}
+
+impl<T> WeightedList<T> {
+    #[inline]
+    pub const fn new() -> WeightedList<T> {
+        WeightedList {
+            entries: Vec::new()
+        }
+    }
+
+    /// Add an answer with a weight. An answer with the weight `0` is never drawn.
+    #[inline]
+    pub fn push(&mut self, answer: Vec<T>, weight: u32) {
+        self.entries.push((answer, weight));
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T> Default for WeightedList<T> {
+    #[inline]
+    fn default() -> Self {
+        WeightedList::new()
+    }
+}
+
+impl<T> FromIterator<(Vec<T>, u32)> for WeightedList<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (Vec<T>, u32)>>(iter: I) -> Self {
+        WeightedList {
+            entries: iter.into_iter().collect()
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> SecretSource<T> for WeightedList<T> {
+    fn draw<S: Scorer<T>, R: Rng + ?Sized>(
+        &mut self,
+        host: &Host<T, S>,
+        rng: &mut R,
+    ) -> Result<Vec<T>, HostError<T>> {
+        let weight_of = |(answer, weight): &(Vec<T>, u32)| {
+            if *weight > 0 && host.check_known_answer(answer).is_ok() {
+                u64::from(*weight)
+            } else {
+                0
+            }
+        };
+
+        let total: u64 = self.entries.iter().map(weight_of).sum();
+
+        if total == 0 {
+            return Err(HostError::SourceEmpty);
+        }
+
+        let mut r = rng.random_range(0..total);
+
+        for entry in self.entries.iter() {
+            let weight = weight_of(entry);
+
+            if r < weight {
+                return Ok(entry.0.clone());
+            }
+
+            r -= weight;
+        }
+
+        unreachable!()
+    }
+}
+
+/// Draw answers from a pool in a random order, so that no answer repeats until all of the answers which the host accepts have been drawn. Then it starts over.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WithoutReplacement<T> {
+    /// The drawn answers are moved to the front.
+    pool:  Vec<Vec<T>>,
+    drawn: usize,
+}
+
+impl<T> WithoutReplacement<T> {
+    #[inline]
+    pub const fn new(pool: Vec<Vec<T>>) -> WithoutReplacement<T> {
+        WithoutReplacement {
+            pool,
+            drawn: 0,
+        }
+    }
+
+    #[inline]
+    pub fn get_pool(&self) -> &[Vec<T>] {
+        &self.pool
+    }
+
+    /// The number of answers which have not been drawn since the pool started over.
+    #[inline]
+    pub fn get_remaining(&self) -> usize {
+        self.pool.len() - self.drawn
+    }
+
+    /// Start over, so that every answer in the pool can be drawn again.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.drawn = 0;
+    }
+}
+
+impl<T: Eq + Hash + Clone> SecretSource<T> for WithoutReplacement<T> {
+    fn draw<S: Scorer<T>, R: Rng + ?Sized>(
+        &mut self,
+        host: &Host<T, S>,
+        rng: &mut R,
+    ) -> Result<Vec<T>, HostError<T>> {
+        let accepted = |pool: &[Vec<T>], from: usize| -> Vec<usize> {
+            (from..pool.len()).filter(|&i| host.check_known_answer(&pool[i]).is_ok()).collect()
+        };
+
+        let mut candidates = accepted(&self.pool, self.drawn);
+
+        if candidates.is_empty() {
+            self.reset();
+
+            candidates = accepted(&self.pool, 0);
+
+            if candidates.is_empty() {
+                return Err(HostError::SourceEmpty);
+            }
+        }
+
+        let index = candidates[rng.random_range(0..candidates.len())];
+
+        self.pool.swap(self.drawn, index);
+        self.drawn += 1;
+
+        Ok(self.pool[self.drawn - 1].clone())
+    }
+}
@@ -1,10 +1,307 @@
-use bulls_and_cows::parser::ABParser;
+use bulls_and_cows::{
+    parser::{ABParser, Notation, ParseError},
+    Score,
+};
 
 #[test]
 fn parse_xayb() {
     let ab = ABParser::new();
 
-    assert_eq!((4, 0), ab.parse("4A0B").unwrap());
-    assert_eq!((2, 1), ab.parse("2a1B").unwrap());
-    assert_eq!((0, 4), ab.parse("0a4b").unwrap());
+    assert_eq!(Score::new(4, 0), ab.parse("4A0B").unwrap());
+    assert_eq!(Score::new(2, 1), ab.parse("2a1B").unwrap());
+    assert_eq!(Score::new(0, 4), ab.parse("0a4b").unwrap());
+}
+
+#[test]
+fn parse_notations() {
+    let ab = ABParser::with_all_notations();
+
+    assert_eq!((Score::new(1, 2), Notation::AB), ab.parse_with_notation("1A2B").unwrap());
+    assert_eq!((Score::new(1, 0), Notation::AB), ab.parse_with_notation("1A").unwrap());
+    assert_eq!((Score::new(0, 0), Notation::AB), ab.parse_with_notation("0a").unwrap());
+    assert_eq!(
+        (Score::new(1, 2), Notation::Words),
+        ab.parse_with_notation("1 bull 2 cows").unwrap()
+    );
+    assert_eq!(
+        (Score::new(0, 1), Notation::Words),
+        ab.parse_with_notation("0 Bulls, 1 Cow").unwrap()
+    );
+    assert_eq!((Score::new(1, 2), Notation::BC), ab.parse_with_notation("1B2C").unwrap());
+    assert_eq!((Score::new(1, 2), Notation::PrefixedBC), ab.parse_with_notation("B1 C2").unwrap());
+    assert_eq!((Score::new(2, 1), Notation::Pegs), ab.parse_with_notation("++-").unwrap());
+    assert_eq!((Score::new(0, 3), Notation::Pegs), ab.parse_with_notation("---").unwrap());
+    assert_eq!((Score::new(0, 0), Notation::Pegs), ab.parse_with_notation("0").unwrap());
+
+    assert_eq!(
+        Err(ParseError::Empty {
+            span: 0..0
+        }),
+        ab.parse("")
+    );
+    assert_eq!(
+        Err(ParseError::MissingNumber {
+            span: 0..1
+        }),
+        ab.parse("-+")
+    );
+    assert_eq!(
+        Err(ParseError::MissingA {
+            span: 2..3
+        }),
+        ab.parse("1 bull")
+    );
+
+    let ab = ABParser::new();
+
+    assert_eq!(
+        Err(ParseError::MissingA {
+            span: 1..2
+        }),
+        ab.parse("1B2C")
+    );
+    assert_eq!(
+        Err(ParseError::MissingNumber {
+            span: 0..1
+        }),
+        ab.parse("++-")
+    );
+}
+
+#[test]
+fn format_notations() {
+    let ab = ABParser::with_all_notations();
+
+    for notation in Notation::ALL {
+        for (bulls, cows) in [(0, 0), (1, 1), (2, 1), (0, 4)] {
+            let score = Score::new(bulls, cows);
+            let s = notation.format(score);
+
+            assert_eq!((score, notation), ab.parse_with_notation(&s).unwrap(), "{}", s);
+        }
+    }
+
+    assert_eq!("1 bull 2 cows", Notation::Words.format(Score::new(1, 2)));
+    assert_eq!("B0 C4", Notation::PrefixedBC.format(Score::new(0, 4)));
+
+    // the echo is normalised
+    let (score, notation) = ab.parse_with_notation("1A").unwrap();
+
+    assert_eq!("1A0B", notation.format(score));
+}
+
+#[test]
+fn parse_with_answer_length() {
+    let ab = ABParser::with_answer_length(4);
+
+    assert_eq!(Score::new(2, 2), ab.parse("2A2B").unwrap());
+    assert_eq!(Score::new(4, 0), ab.parse("4A0B").unwrap());
+    assert_eq!(
+        Err(ParseError::TooManyLetters {
+            span: 0..4, score: Score::new(3, 2)
+        }),
+        ab.parse("3A2B")
+    );
+    assert_eq!(
+        Err(ParseError::Impossible {
+            span: 1..5, score: Score::new(3, 1)
+        }),
+        ab.parse(" 3A1B ")
+    );
+    assert_eq!(
+        Err(ParseError::TooManyLetters {
+            span: 0..4, score: Score::new(7, 9)
+        }),
+        ab.parse("7A9B")
+    );
+    assert_eq!(
+        Err(ParseError::TooManyLetters {
+            span: 0..4, score: Score::new(0, 9)
+        }),
+        ab.parse("0A9B")
+    );
+
+    let mut ab = ABParser::with_all_notations();
+
+    ab.set_answer_length(Some(3));
+
+    assert_eq!(
+        Err(ParseError::Impossible {
+            span: 0..3, score: Score::new(2, 1)
+        }),
+        ab.parse("++-")
+    );
+    assert_eq!(
+        Err(ParseError::TooManyLetters {
+            span: 0..5, score: Score::new(2, 2)
+        }),
+        ab.parse("B2 C2")
+    );
+
+    // the same reply gives the same error in every notation
+    ab.set_answer_length(Some(4));
+
+    assert_eq!(
+        Err(ParseError::TooManyLetters {
+            span: 0..4, score: Score::new(7, 9)
+        }),
+        ab.parse("7A9B")
+    );
+    assert_eq!(
+        Err(ParseError::TooManyLetters {
+            span: 0..16, score: Score::new(7, 9)
+        }),
+        ab.parse("+++++++---------")
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let ab = ABParser::new();
+
+    assert_eq!(
+        Err(ParseError::Empty {
+            span: 2..2
+        }),
+        ab.parse("  ")
+    );
+    assert_eq!(
+        Err(ParseError::MissingNumber {
+            span: 0..1
+        }),
+        ab.parse("A2B")
+    );
+    assert_eq!(
+        Err(ParseError::MissingNumber {
+            span: 2..3
+        }),
+        ab.parse("1AB")
+    );
+    assert_eq!(
+        Err(ParseError::MissingA {
+            span: 1..2
+        }),
+        ab.parse("1x2B")
+    );
+    assert_eq!(
+        Err(ParseError::MissingA {
+            span: 1..1
+        }),
+        ab.parse("1")
+    );
+    assert_eq!(
+        Err(ParseError::MissingB {
+            span: 3..3
+        }),
+        ab.parse("1A2")
+    );
+    assert_eq!(
+        Err(ParseError::MissingB {
+            span: 4..5
+        }),
+        ab.parse(" 1A2C")
+    );
+    assert_eq!(
+        Err(ParseError::TrailingGarbage {
+            span: 4..6
+        }),
+        ab.parse("1A2B!!")
+    );
+    assert_eq!(
+        Err(ParseError::TrailingGarbage {
+            span: 3..4
+        }),
+        ab.parse("1A x")
+    );
+    assert_eq!(
+        Err(ParseError::MissingNumber {
+            span: 0..2
+        }),
+        ab.parse("\u{0663}A0B")
+    );
+
+    let s = format!("1A{}0B", usize::MAX);
+
+    assert_eq!(
+        Err(ParseError::NumberOutOfRange {
+            span: 2..(s.len() - 1)
+        }),
+        ab.parse(&s)
+    );
+
+    let ab = ABParser::with_notations(&[Notation::BC]);
+
+    assert_eq!(
+        Err(ParseError::Unrecognized {
+            span: 0..4
+        }),
+        ab.parse("1A2B")
+    );
+}
+
+#[test]
+fn parse_like_regexes() {
+    use regex::Regex;
+
+    // the reference behavior which the hand-written matcher must agree with
+    let regexes: Vec<(Notation, Regex)> = [
+        (Notation::AB, r"^([0-9]+)[ ]*[aA](?:[ ]*([0-9]+)[ ]*[bB])?$"),
+        (Notation::Words, r"^([0-9]+)[ ]*(?i-u:bulls?)[ ]*,?[ ]*([0-9]+)[ ]*(?i-u:cows?)$"),
+        (Notation::BC, r"^([0-9]+)[ ]*[bB][ ]*([0-9]+)[ ]*[cC]$"),
+        (Notation::PrefixedBC, r"^[bB][ ]*([0-9]+)[ ]*[cC][ ]*([0-9]+)$"),
+        (Notation::Pegs, r"^(?:(\+*)(-*)|0)$"),
+    ]
+    .into_iter()
+    .map(|(notation, regex)| (notation, Regex::new(regex).unwrap()))
+    .collect();
+
+    let reference = |s: &str| {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return None;
+        }
+
+        regexes.iter().find_map(|(notation, regex)| {
+            let captures = regex.captures(s)?;
+
+            let score = if *notation == Notation::Pegs {
+                Score::new(
+                    captures.get(1).map(|m| m.len()).unwrap_or(0),
+                    captures.get(2).map(|m| m.len()).unwrap_or(0),
+                )
+            } else {
+                let number = |i| captures.get(i).map(|m| m.as_str().parse().unwrap()).unwrap_or(0);
+
+                Score::new(number(1), number(2))
+            };
+
+            Some((score, *notation))
+        })
+    };
+
+    let tokens = [
+        "", "0", "1", "12", " ", "a", "A", "b", "B", "c", "C", "bull", "Bulls", "cow", "COWS", ",",
+        "+", "-", "x", "\u{017F}",
+    ];
+
+    let ab = ABParser::with_all_notations();
+
+    let mut s = String::new();
+
+    for t1 in tokens {
+        for t2 in tokens {
+            for t3 in tokens {
+                for t4 in tokens {
+                    s.clear();
+                    s.push_str(t1);
+                    s.push_str(t2);
+                    s.push_str(t3);
+                    s.push_str(t4);
+
+                    assert_eq!(reference(&s), ab.parse_with_notation(&s).ok(), "{:?}", s);
+                }
+            }
+        }
+    }
 }
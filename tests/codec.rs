@@ -0,0 +1,66 @@
+use bulls_and_cows::{
+    parser::{AlphabetCodec, Codec, CodecError},
+    Letters,
+};
+
+#[test]
+fn decode() {
+    let codec = AlphabetCodec::new(Letters::generate_numeric_letters());
+
+    assert_eq!(vec![1, 2, 3, 4], codec.decode("1234").unwrap());
+    assert_eq!(vec![1, 2, 3, 4], codec.decode(" 1 2  3 4 ").unwrap());
+    assert_eq!(vec![1, 2, 3, 4], codec.decode("1, 2,3 ,4").unwrap());
+
+    assert_eq!(
+        CodecError {
+            position: 2, span: 2..3, token: "x".to_string()
+        },
+        codec.decode("12x4").unwrap_err()
+    );
+    assert_eq!(
+        CodecError {
+            position: 1, span: 3..5, token: "23".to_string()
+        },
+        codec.decode(" 1 23 4").unwrap_err()
+    );
+    assert_eq!(
+        CodecError {
+            position: 1, span: 2..2, token: String::new()
+        },
+        codec.decode("1,,2").unwrap_err()
+    );
+
+    let codec = AlphabetCodec::new(Letters::from_chars("RGBYOP").unwrap());
+
+    assert_eq!(vec!['R', 'G', 'B', 'Y'], codec.decode("R,G,B,Y").unwrap());
+    assert_eq!(
+        CodecError {
+            position: 3, span: 6..7, token: "W".to_string()
+        },
+        codec.decode("R,G,B,W").unwrap_err()
+    );
+}
+
+#[test]
+fn encode() {
+    let codec = AlphabetCodec::new(Letters::generate_numeric_letters());
+
+    assert_eq!("1234", codec.encode(&[1, 2, 3, 4]));
+
+    let mut codec = AlphabetCodec::with_symbols(Letters::from_chars("RGB").unwrap(), |c| {
+        match c {
+            'R' => "red",
+            'G' => "green",
+            _ => "blue",
+        }
+        .to_string()
+    });
+
+    assert_eq!("red green blue", codec.encode(&['R', 'G', 'B']));
+    assert_eq!(vec!['B', 'R'], codec.decode("bluered").unwrap());
+
+    codec.set_separator(",");
+
+    assert_eq!("red,green", codec.encode(&['R', 'G']));
+    assert_eq!(vec!['R', 'G'], codec.decode(&codec.encode(&['R', 'G'])).unwrap());
+}
@@ -0,0 +1,178 @@
+#![cfg(feature = "commitment")]
+
+use bulls_and_cows::{
+    commitment::{self, Commitment, CommitmentError, Opening},
+    scorer::{BullsAndCows, HiddenLength, HiddenLengthReply, LengthHint},
+    Host, Letters, Score,
+};
+
+#[test]
+fn commit_and_verify() {
+    let mut host =
+        Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3, 4])
+            .unwrap();
+
+    assert_eq!(None, host.get_commitment());
+    assert_eq!(None, host.open_commitment());
+
+    #[cfg(feature = "std")]
+    let commitment = host.commit().unwrap();
+    #[cfg(not(feature = "std"))]
+    let commitment = host.commit_with_salt([3; commitment::SALT_LENGTH]).unwrap();
+
+    assert_eq!(Some(commitment), host.get_commitment());
+    assert_eq!(64, commitment.to_string().len());
+
+    let mut transcript = Vec::new();
+
+    for guess in [vec![5, 6, 7, 8], vec![4, 3, 2, 1], vec![1, 2, 4, 3], vec![1, 2, 3, 4]] {
+        let reply = host.answer(&guess).unwrap();
+
+        transcript.push((guess, reply));
+    }
+
+    let opening = host.open_commitment().unwrap();
+
+    assert_eq!(&[1, 2, 3, 4], opening.get_answer());
+
+    let letters = host.get_letters();
+
+    assert_eq!(
+        Ok(()),
+        commitment::verify(letters, false, &BullsAndCows, &commitment, &opening, &transcript)
+    );
+
+    // a host which lied about a reply
+    transcript[2].1 = Score::new(1, 3);
+
+    assert_eq!(
+        Err(CommitmentError::ReplyMismatch {
+            index: 2
+        }),
+        commitment::verify(letters, false, &BullsAndCows, &commitment, &opening, &transcript)
+    );
+
+    // a host which changed the answer
+    let other_opening = Opening::new(*opening.get_salt(), vec![1, 2, 4, 3]);
+
+    assert_eq!(
+        Err(CommitmentError::AnswerMismatch),
+        commitment::verify(letters, false, &BullsAndCows, &commitment, &other_opening, &transcript)
+    );
+
+    transcript[2].0 = vec![1, 2, 3];
+
+    assert_eq!(
+        Err(CommitmentError::GuessIncorrect {
+            index: 2
+        }),
+        commitment::verify(letters, false, &BullsAndCows, &commitment, &opening, &transcript)
+    );
+
+    // a guess which the mode does not allow
+    transcript[2].0 = vec![1, 1, 1, 1];
+
+    assert_eq!(
+        Err(CommitmentError::GuessIncorrect {
+            index: 2
+        }),
+        commitment::verify(letters, false, &BullsAndCows, &commitment, &opening, &transcript)
+    );
+
+    // a host which lied about the mode
+    assert_eq!(
+        Err(CommitmentError::AnswerMismatch),
+        commitment::verify(letters, true, &BullsAndCows, &commitment, &opening, &transcript)
+    );
+
+    host.renew_with_known_answer(vec![1, 2, 4, 3]).unwrap();
+
+    assert_eq!(None, host.get_commitment());
+}
+
+#[test]
+fn commit_with_salt() {
+    let salt = [7; commitment::SALT_LENGTH];
+
+    let mut host =
+        Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3, 4])
+            .unwrap();
+
+    let commitment = host.commit_with_salt(salt).unwrap();
+
+    // the letters are committed by their indices
+    let letters = Letters::from_chars("0123456789").unwrap();
+
+    assert_eq!(Some(commitment), Commitment::new(&letters, false, &salt, &['1', '2', '3', '4']));
+    assert_ne!(
+        Some(commitment),
+        Commitment::new(&letters, false, &[8; commitment::SALT_LENGTH], &['1', '2', '3', '4'])
+    );
+    assert_eq!(None, Commitment::new(&letters, false, &salt, &['1', '2', '3', 'x']));
+
+    // the mode is committed as well
+    assert_ne!(Some(commitment), Commitment::new(&letters, true, &salt, &['1', '2', '3', '4']));
+    assert_eq!(commitment, Commitment::from_bytes(*commitment.as_bytes()));
+}
+
+#[test]
+fn hidden_length() {
+    let mut host =
+        Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3])
+            .unwrap()
+            .into_hidden_length();
+
+    let commitment = host.commit_with_salt([5; commitment::SALT_LENGTH]).unwrap();
+
+    let mut transcript = Vec::new();
+
+    for guess in [vec![1, 2], vec![3, 2, 1, 0], vec![1, 2, 3]] {
+        let reply = host.answer(&guess).unwrap();
+
+        transcript.push((guess, reply));
+    }
+
+    let opening = host.open_commitment().unwrap();
+    let letters = host.get_letters();
+    let scorer = HiddenLength::new(BullsAndCows);
+
+    assert_eq!(
+        Ok(()),
+        commitment::verify(letters, false, &scorer, &commitment, &opening, &transcript)
+    );
+
+    // a host which lied about the length
+    transcript[0].1 = HiddenLengthReply {
+        reply: Score::new(2, 0), length: LengthHint::Exact
+    };
+
+    assert_eq!(
+        Err(CommitmentError::ReplyMismatch {
+            index: 0
+        }),
+        commitment::verify(letters, false, &scorer, &commitment, &opening, &transcript)
+    );
+
+    transcript[0].0 = vec![];
+
+    assert_eq!(
+        Err(CommitmentError::GuessIncorrect {
+            index: 0
+        }),
+        commitment::verify(letters, false, &scorer, &commitment, &opening, &transcript)
+    );
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn invalid_trusted_answer() {
+    use bulls_and_cows::HostError;
+
+    let mut host = Host::from_trusted(Letters::generate_numeric_letters(), vec![1, 2, 10]);
+
+    assert!(matches!(
+        host.commit_with_salt([1; commitment::SALT_LENGTH]),
+        Err(HostError::AnswerContainsIncorrectLetter { .. })
+    ));
+    assert_eq!(None, host.get_commitment());
+}
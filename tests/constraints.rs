@@ -0,0 +1,141 @@
+use bulls_and_cows::{Constraints, Host, HostError, Letters};
+
+#[test]
+fn no_leading_zero() {
+    let mut constraints = Constraints::new();
+
+    constraints.forbid(0, 0);
+
+    let mut host = Host::build_with_known_answer_constraints(
+        Letters::generate_numeric_letters(),
+        vec![1, 0, 2, 3],
+        constraints,
+    )
+    .unwrap();
+
+    host.set_seed(1);
+
+    for _ in 0..1000 {
+        host.renew_with_random_answer(4).unwrap();
+
+        assert_ne!(0, host.get_answer()[0]);
+    }
+}
+
+#[test]
+fn known_answer() {
+    let mut constraints = Constraints::new();
+
+    constraints.forbid(0, 0);
+    constraints.require(5);
+
+    let mut host = Host::build_with_known_answer_constraints(
+        Letters::generate_numeric_letters(),
+        vec![1, 2, 3, 5],
+        constraints,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        host.renew_with_known_answer(vec![0, 1, 2, 5]),
+        Err(HostError::AnswerBreaksConstraints)
+    ));
+    assert!(matches!(
+        host.renew_with_known_answer(vec![1, 2, 3, 4]),
+        Err(HostError::AnswerBreaksConstraints)
+    ));
+    assert_eq!(&[1, 2, 3, 5], host.get_answer());
+
+    let mut constraints = Constraints::new();
+
+    constraints.set_predicate(|answer: &[u8]| answer.iter().all(|letter| letter % 2 == 0));
+
+    assert!(matches!(host.set_constraints(constraints), Err(HostError::AnswerBreaksConstraints)));
+
+    host.set_constraints(Constraints::new()).unwrap();
+    host.renew_with_known_answer(vec![0, 1, 2, 4]).unwrap();
+}
+
+#[test]
+fn rare_answers() {
+    const TIMES: usize = 4000;
+
+    let mut constraints = Constraints::new();
+
+    // only [0, 3], [1, 2], [2, 1] and [3, 0] are allowed
+    constraints.set_predicate(|answer: &[u8]| answer.iter().sum::<u8>() == 3);
+
+    let mut host = Host::build_with_known_answer_constraints(
+        Letters::generate_numeric_letters(),
+        vec![0, 3],
+        constraints,
+    )
+    .unwrap();
+
+    host.set_seed(2);
+
+    let mut counts = [0usize; 4];
+
+    for _ in 0..TIMES {
+        host.renew_with_random_answer(2).unwrap();
+
+        let answer = host.get_answer();
+
+        assert_eq!(3, answer[0] + answer[1]);
+
+        counts[answer[0] as usize] += 1;
+    }
+
+    // every allowed answer is expected to be drawn in 1/4 of the rounds
+    let expected = TIMES / 4;
+
+    for &count in counts.iter() {
+        assert!(count > expected * 4 / 5 && count < expected * 6 / 5, "{:?}", counts);
+    }
+}
+
+#[test]
+fn unsatisfiable() {
+    let mut constraints = Constraints::new();
+
+    constraints.require(10);
+
+    // without `std`, a random answer needs a seed
+    #[cfg(feature = "std")]
+    assert!(matches!(
+        Host::build_with_random_answer_constraints(
+            Letters::generate_numeric_letters(),
+            3,
+            constraints
+        ),
+        Err(HostError::ConstraintsUnsatisfiable)
+    ));
+
+    // these are found without enumerating the answers, which would take forever
+    let mut host = Host::build(Letters::generate_numeric_letters(), 4).unwrap();
+
+    host.set_seed(3);
+    host.set_duplicated_letters_allowed(true).unwrap();
+
+    let mut constraints = Constraints::new();
+
+    for letter in 0..10 {
+        constraints.forbid(5, letter);
+    }
+
+    host.set_constraints(constraints).unwrap();
+
+    assert!(matches!(host.renew_with_random_answer(20), Err(HostError::ConstraintsUnsatisfiable)));
+
+    host.set_duplicated_letters_allowed(false).unwrap();
+
+    let mut constraints = Constraints::new();
+
+    for letter in 1..=3 {
+        constraints.require(letter);
+    }
+
+    host.set_constraints(constraints).unwrap();
+
+    assert!(matches!(host.renew_with_random_answer(2), Err(HostError::ConstraintsUnsatisfiable)));
+}
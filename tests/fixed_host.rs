@@ -0,0 +1,106 @@
+use bulls_and_cows::{FixedHost, Host, HostError, Letters, Score};
+
+#[test]
+fn known_answer() {
+    let host =
+        FixedHost::build_with_known_answer(Letters::generate_numeric_letters(), [1, 2, 3, 4])
+            .unwrap();
+
+    assert_eq!(Score::new(4, 0), host.answer(&[1, 2, 3, 4]).unwrap());
+    assert_eq!(Score::new(2, 2), host.answer(&[1, 2, 4, 3]).unwrap());
+    assert_eq!(Score::new(0, 4), host.answer(&[4, 3, 2, 1]).unwrap());
+    assert_eq!(Score::new(0, 0), host.answer(&[5, 6, 7, 8]).unwrap());
+    assert!(host.is_win(&host.answer(&[1, 2, 3, 4]).unwrap()));
+
+    assert_eq!(
+        Err(HostError::AnswerContainsIncorrectLetter {
+            index: 3, letter: 10
+        }),
+        host.answer(&[1, 2, 3, 10])
+    );
+    assert_eq!(
+        Err(HostError::AnswerContainsDuplicatedLetter {
+            index:       3,
+            first_index: 0,
+            letter:      1,
+        }),
+        host.answer(&[1, 2, 3, 1])
+    );
+
+    assert_eq!(
+        HostError::AnswerContainsDuplicatedLetter {
+            index:       2,
+            first_index: 0,
+            letter:      1,
+        },
+        FixedHost::build_with_known_answer(Letters::generate_numeric_letters(), [1, 2, 1])
+            .unwrap_err()
+    );
+
+    let host = Host::build(Letters::generate_numeric_letters(), 3).unwrap();
+
+    assert_eq!(
+        HostError::AnswerLengthIncorrect {
+            expected: 4..=4, actual: 3
+        },
+        FixedHost::<u8, 4>::from_host(&host).unwrap_err()
+    );
+}
+
+#[test]
+fn same_as_host() {
+    let letters = Letters::from_iter([1u8, 2, 3, 4, 5]).unwrap();
+
+    for duplicated_letters_allowed in [false, true] {
+        let mut host = Host::build(letters.clone(), 3).unwrap();
+
+        host.set_duplicated_letters_allowed(duplicated_letters_allowed).unwrap();
+
+        for a in 0..6 {
+            for b in 0..6 {
+                for c in 0..6 {
+                    let answer = vec![a, b, c];
+
+                    if host.renew_with_known_answer(answer).is_err() {
+                        continue;
+                    }
+
+                    let fixed_host = FixedHost::<u8, 3>::from_host(&host).unwrap();
+
+                    for x in 0..6 {
+                        for y in 0..6 {
+                            for z in 0..6 {
+                                let guess = [x, y, z];
+
+                                assert_eq!(host.answer(&guess), fixed_host.answer(&guess));
+                                assert_eq!(
+                                    host.answer_detailed(&guess),
+                                    fixed_host.answer_detailed(&guess).map(|f| f.to_vec())
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize_from_host() {
+    let mut host =
+        Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3]).unwrap();
+
+    assert!(!FixedHost::<u8, 3>::from_host(&host).unwrap().is_zeroize());
+
+    host.set_zeroize(true);
+
+    let mut fixed_host = FixedHost::<u8, 3>::from_host(&host).unwrap();
+
+    assert!(fixed_host.is_zeroize());
+
+    fixed_host.renew_with_known_answer([4, 5, 6]).unwrap();
+
+    assert_eq!(&[4, 5, 6], fixed_host.get_answer());
+}
@@ -1,3 +1,6 @@
+use bulls_and_cows::Score;
+
+#[cfg(feature = "std")]
 #[test]
 fn random_answer() {
     let host = bulls_and_cows::Host::build_with_random_answer(
@@ -17,8 +20,438 @@ fn known_answer() {
     )
     .unwrap();
 
-    assert_eq!((4, 0), host.answer(&[1, 2, 3, 4]).unwrap());
-    assert_eq!((2, 2), host.answer(&[1, 2, 4, 3]).unwrap());
-    assert_eq!((0, 4), host.answer(&[4, 3, 2, 1]).unwrap());
-    assert_eq!((0, 0), host.answer(&[5, 6, 7, 8]).unwrap());
+    assert_eq!(Score::new(4, 0), host.answer(&[1, 2, 3, 4]).unwrap());
+    assert_eq!(Score::new(2, 2), host.answer(&[1, 2, 4, 3]).unwrap());
+    assert_eq!(Score::new(0, 4), host.answer(&[4, 3, 2, 1]).unwrap());
+    assert_eq!(Score::new(0, 0), host.answer(&[5, 6, 7, 8]).unwrap());
+}
+
+#[test]
+fn duplicated_letters() {
+    let mut host = bulls_and_cows::Host::build_with_known_answer(
+        bulls_and_cows::Letters::generate_numeric_letters(),
+        vec![1, 2, 3, 4],
+    )
+    .unwrap();
+
+    assert!(host.renew_with_known_answer(vec![1, 1, 2, 2]).is_err());
+    assert!(host.answer(&[1, 1, 2, 2]).is_err());
+
+    host.set_duplicated_letters_allowed(true).unwrap();
+
+    host.renew_with_known_answer(vec![1, 1, 2, 3]).unwrap();
+
+    assert_eq!(Score::new(4, 0), host.answer(&[1, 1, 2, 3]).unwrap());
+    assert_eq!(Score::new(1, 0), host.answer(&[1, 5, 5, 5]).unwrap());
+    assert_eq!(Score::new(0, 1), host.answer(&[5, 5, 1, 5]).unwrap());
+    assert_eq!(Score::new(0, 3), host.answer(&[2, 3, 1, 5]).unwrap());
+    assert_eq!(Score::new(1, 1), host.answer(&[1, 5, 5, 1]).unwrap());
+    assert_eq!(Score::new(2, 0), host.answer(&[1, 2, 2, 2]).unwrap());
+    assert_eq!(Score::new(0, 4), host.answer(&[2, 3, 1, 1]).unwrap());
+    assert_eq!(Score::new(0, 1), host.answer(&[3, 3, 3, 5]).unwrap());
+
+    assert!(host.set_duplicated_letters_allowed(false).is_err());
+
+    host.set_seed(0);
+    host.renew_with_random_answer(12).unwrap();
+
+    assert_eq!(12, host.get_answer_length());
+
+    assert_eq!(1..=bulls_and_cows::MAX_ANSWER_LENGTH, host.get_answer_length_range());
+    assert!(matches!(
+        host.renew_with_random_answer(usize::MAX),
+        Err(bulls_and_cows::HostError::AnswerLengthIncorrect { .. })
+    ));
+    assert!(matches!(
+        bulls_and_cows::play::players::ComputerGuesser::new(&host, 0),
+        Err(bulls_and_cows::HostError::AnswerLengthIncorrect { .. })
+    ));
+}
+
+#[cfg(not(feature = "std"))]
+#[test]
+fn unseeded_random_answer() {
+    use bulls_and_cows::{
+        play::players::{ComputerGuesser, ComputerQuestioner, Guesser, Questioner},
+        Host, HostError, Letters,
+    };
+
+    assert!(matches!(
+        Host::build_with_random_answer(Letters::generate_numeric_letters(), 4),
+        Err(HostError::RngUnseeded)
+    ));
+
+    let mut host =
+        Host::build_with_random_answer_seed(Letters::generate_numeric_letters(), 4, 1).unwrap();
+
+    host.renew_with_random_answer(4).unwrap();
+
+    let mut guesser = ComputerGuesser::new(&host, 0).unwrap();
+
+    assert!(matches!(guesser.guess(), Err(HostError::RngUnseeded)));
+
+    guesser.set_seed(1);
+
+    guesser.guess().unwrap();
+
+    let unseeded_host =
+        Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3, 4])
+            .unwrap();
+
+    assert!(matches!(ComputerQuestioner::new(unseeded_host, 0), Err(HostError::RngUnseeded)));
+
+    let mut questioner = ComputerQuestioner::new(host, 0).unwrap();
+
+    questioner.make_new_question();
+}
+
+#[test]
+fn seeded_random_answer() {
+    let mut host = bulls_and_cows::Host::build_with_random_answer_seed(
+        bulls_and_cows::Letters::generate_numeric_letters(),
+        4,
+        42,
+    )
+    .unwrap();
+
+    let mut answers = vec![host.get_answer().to_vec()];
+
+    for _ in 0..9 {
+        host.renew_with_random_answer(4).unwrap();
+
+        answers.push(host.get_answer().to_vec());
+    }
+
+    host.set_seed(42);
+
+    for answer in answers.iter() {
+        host.renew_with_random_answer(4).unwrap();
+
+        assert_eq!(answer.as_slice(), host.get_answer());
+    }
+
+    let other_host = bulls_and_cows::Host::build_with_random_answer_seed(
+        bulls_and_cows::Letters::generate_numeric_letters(),
+        4,
+        42,
+    )
+    .unwrap();
+
+    assert_eq!(answers[0].as_slice(), other_host.get_answer());
+
+    let mut rng_1 = <rand_chacha::ChaCha8Rng as rand::SeedableRng>::seed_from_u64(7);
+    let mut rng_2 = <rand_chacha::ChaCha8Rng as rand::SeedableRng>::seed_from_u64(7);
+
+    for _ in 0..10 {
+        host.renew_with_random_answer_rng(4, &mut rng_1).unwrap();
+
+        let answer = host.get_answer().to_vec();
+
+        host.renew_with_random_answer_rng(4, &mut rng_2).unwrap();
+
+        assert_eq!(answer.as_slice(), host.get_answer());
+    }
+}
+
+#[test]
+fn uniform_random_answer() {
+    const TIMES: usize = 5000;
+
+    for duplicated_letters_allowed in [false, true] {
+        let letters = bulls_and_cows::Letters::generate_numeric_letters();
+
+        let mut host = bulls_and_cows::Host::build(letters, 4).unwrap();
+
+        host.set_duplicated_letters_allowed(duplicated_letters_allowed).unwrap();
+
+        let mut rng = <rand_chacha::ChaCha8Rng as rand::SeedableRng>::seed_from_u64(3);
+
+        let mut counts = [[0usize; 10]; 4];
+
+        for _ in 0..TIMES {
+            host.renew_with_random_answer_rng(4, &mut rng).unwrap();
+
+            for (i, &letter) in host.get_answer().iter().enumerate() {
+                counts[i][letter as usize] += 1;
+            }
+        }
+
+        // every letter is expected to be at every position in 1/10 of the answers
+        let expected = TIMES / 10;
+
+        for position_counts in counts.iter() {
+            for &count in position_counts.iter() {
+                assert!(count > expected * 4 / 5 && count < expected * 6 / 5, "{:?}", counts);
+            }
+        }
+    }
+}
+
+#[test]
+fn detailed_answer() {
+    use bulls_and_cows::Feedback::{Bull, Cow, Miss};
+
+    let mut host = bulls_and_cows::Host::build_with_known_answer(
+        bulls_and_cows::Letters::generate_numeric_letters(),
+        vec![4, 2, 7, 1],
+    )
+    .unwrap();
+
+    assert_eq!(vec![Cow, Bull, Miss, Cow], host.answer_detailed(&[1, 2, 3, 4]).unwrap());
+    assert_eq!(vec![Bull, Bull, Bull, Bull], host.answer_detailed(&[4, 2, 7, 1]).unwrap());
+    assert!(host.answer_detailed(&[1, 2, 3]).is_err());
+    assert!(host.answer_detailed(&[1, 1, 3, 4]).is_err());
+
+    host.set_duplicated_letters_allowed(true).unwrap();
+    host.renew_with_known_answer(vec![1, 1, 2, 3]).unwrap();
+
+    assert_eq!(vec![Miss, Cow, Miss, Bull], host.answer_detailed(&[3, 2, 3, 3]).unwrap());
+    assert_eq!(vec![Cow, Miss, Miss, Miss], host.answer_detailed(&[3, 3, 5, 5]).unwrap());
+    assert_eq!(vec![Bull, Cow, Miss, Miss], host.answer_detailed(&[1, 2, 5, 2]).unwrap());
+}
+
+#[test]
+fn batch_answer() {
+    let host = bulls_and_cows::Host::build_with_known_answer(
+        bulls_and_cows::Letters::generate_numeric_letters(),
+        vec![1, 2, 3, 4],
+    )
+    .unwrap();
+
+    let mut replies = vec![Score::new(9, 9)];
+
+    host.answer_many(&[[1, 2, 3, 4], [1, 2, 4, 3], [4, 3, 2, 1], [5, 6, 7, 8]], &mut replies)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            Score::new(9, 9),
+            Score::new(4, 0),
+            Score::new(2, 2),
+            Score::new(0, 4),
+            Score::new(0, 0)
+        ],
+        replies
+    );
+
+    replies.clear();
+
+    assert_eq!(
+        Err(bulls_and_cows::HostError::AnswerContainsDuplicatedLetter {
+            index:       1,
+            first_index: 0,
+            letter:      1,
+        }),
+        host.answer_many(&[vec![1, 2, 3, 4], vec![1, 1, 2, 3]], &mut replies)
+    );
+    assert!(replies.is_empty());
+
+    let candidates = [[1, 2, 3, 4], [4, 3, 2, 1], [5, 6, 7, 8], [1, 5, 2, 6]];
+
+    host.score_candidates(&[1, 2, 5, 6], &candidates, &mut replies).unwrap();
+
+    assert_eq!(
+        vec![Score::new(2, 0), Score::new(0, 2), Score::new(0, 2), Score::new(2, 2)],
+        replies
+    );
+
+    replies.clear();
+
+    assert_eq!(
+        Err(bulls_and_cows::HostError::AnswerContainsIncorrectLetter {
+            index: 3, letter: 10
+        }),
+        host.score_candidates(&[1, 2, 5, 6], &[[1, 2, 3, 4], [1, 2, 3, 10]], &mut replies)
+    );
+    assert_eq!(
+        Err(bulls_and_cows::HostError::AnswerLengthIncorrect {
+            expected: 4..=4, actual: 3
+        }),
+        host.score_candidates(&[1, 2, 5], &[[1, 2, 3, 4]], &mut replies)
+    );
+    assert!(replies.is_empty());
+
+    // the same replies without the packed forms
+    let mut host = host;
+
+    host.set_duplicated_letters_allowed(true).unwrap();
+
+    let candidates =
+        [vec![1, 2, 3, 4], vec![4, 3, 2, 1], vec![5, 6, 7, 8], vec![1, 5, 2, 6], vec![1, 1, 2, 2]];
+
+    host.score_candidates(&[1, 2, 5, 6], &candidates, &mut replies).unwrap();
+
+    assert_eq!(
+        vec![
+            Score::new(2, 0),
+            Score::new(0, 2),
+            Score::new(0, 2),
+            Score::new(2, 2),
+            Score::new(1, 1)
+        ],
+        replies
+    );
+}
+
+#[test]
+fn trusted_answer() {
+    let mut host = bulls_and_cows::Host::from_trusted(
+        bulls_and_cows::Letters::generate_numeric_letters(),
+        vec![1, 2, 3, 4],
+    );
+
+    assert_eq!(Score::new(2, 2), host.answer(&[1, 2, 4, 3]).unwrap());
+
+    let answer = host.validate_answer(vec![5, 6, 7, 8]).unwrap();
+
+    assert_eq!(&[5, 6, 7, 8], answer.as_slice());
+
+    host.renew_with_validated_answer(answer);
+
+    assert_eq!(&[5, 6, 7, 8], host.get_answer());
+
+    assert_eq!(
+        bulls_and_cows::HostError::AnswerContainsDuplicatedLetter {
+            index:       3,
+            first_index: 0,
+            letter:      5,
+        },
+        host.validate_answer(vec![5, 6, 7, 5]).unwrap_err()
+    );
+    assert_eq!(
+        bulls_and_cows::HostError::AnswerLengthIncorrect {
+            expected: 1..=10, actual: 0
+        },
+        host.validate_answer(vec![]).unwrap_err()
+    );
+
+    host.renew_with_trusted_answer(vec![4, 3, 2, 1]);
+
+    assert_eq!(Score::new(0, 4), host.answer(&[1, 2, 3, 4]).unwrap());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the trusted answer is invalid")]
+fn trusted_invalid_answer() {
+    bulls_and_cows::Host::from_trusted(bulls_and_cows::Letters::generate_numeric_letters(), vec![
+        1, 2, 3, 10,
+    ]);
+}
+
+#[test]
+fn hidden_answer() {
+    let mut host = bulls_and_cows::Host::build_with_known_answer(
+        bulls_and_cows::Letters::from_iter([1u8, 2, 3, 4, 5]).unwrap(),
+        vec![5, 4, 3, 2],
+    )
+    .unwrap();
+
+    let debug = format!("{:?}", host);
+
+    assert!(debug.contains("answer: <hidden>"), "{}", debug);
+    assert!(!debug.contains("[5, 4, 3, 2]"), "{}", debug);
+    assert!(format!("{:?}", host.reveal()).contains("answer: [5, 4, 3, 2]"));
+
+    let answer = host.validate_answer(vec![1, 2, 3, 4]).unwrap();
+
+    assert!(!format!("{:?}", answer).contains("[1, 2, 3, 4]"));
+    assert!(format!("{:?}", answer.reveal()).contains("[1, 2, 3, 4]"));
+
+    host.set_seed(0);
+
+    let questioner = bulls_and_cows::play::players::ComputerQuestioner::new(host, 0).unwrap();
+
+    assert!(!format!("{:?}", questioner).contains("[5, 4, 3, 2]"));
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize_answer() {
+    let mut host = bulls_and_cows::Host::build_with_known_answer(
+        bulls_and_cows::Letters::generate_numeric_letters(),
+        vec![1, 2, 3, 4],
+    )
+    .unwrap();
+
+    assert!(!host.is_zeroize());
+
+    host.set_zeroize(true);
+
+    assert!(host.is_zeroize());
+
+    host.renew_with_known_answer(vec![5, 6, 7, 8]).unwrap();
+
+    assert_eq!(&[5, 6, 7, 8], host.get_answer());
+    assert_eq!(Score::new(2, 0), host.answer(&[5, 6, 0, 1]).unwrap());
+
+    let validated_answer = host.validate_answer(vec![1, 2, 3, 4]).unwrap();
+
+    assert_eq!(vec![1, 2, 3, 4], validated_answer.clone().into_inner());
+
+    host.renew_with_validated_answer(validated_answer);
+
+    assert_eq!(&[1, 2, 3, 4], host.get_answer());
+
+    let host = host.with_scorer(bulls_and_cows::scorer::BullsOnly);
+
+    assert!(host.is_zeroize());
+    assert_eq!(&[1, 2, 3, 4], host.get_answer());
+}
+
+#[test]
+fn guess_problems() {
+    use bulls_and_cows::HostError;
+
+    let host = bulls_and_cows::Host::build_with_known_answer(
+        bulls_and_cows::Letters::generate_numeric_letters(),
+        vec![1, 2, 3, 4],
+    )
+    .unwrap();
+
+    assert_eq!(Ok(()), host.validate_guess(&[4, 3, 2, 1]));
+    assert_eq!(
+        Err(vec![
+            HostError::AnswerLengthIncorrect {
+                expected: 4..=4, actual: 5
+            },
+            HostError::AnswerContainsIncorrectLetter {
+                index: 1, letter: 10
+            },
+            HostError::AnswerContainsDuplicatedLetter {
+                index:       2,
+                first_index: 0,
+                letter:      1,
+            },
+            HostError::AnswerContainsDuplicatedLetter {
+                index:       4,
+                first_index: 0,
+                letter:      1,
+            },
+        ]),
+        host.validate_guess(&[1, 10, 1, 2, 1])
+    );
+
+    // the first problem is the error of answering
+    assert_eq!(
+        Err(HostError::AnswerContainsIncorrectLetter {
+            index: 1, letter: 10
+        }),
+        host.answer(&[1, 10, 1, 2])
+    );
+
+    assert_eq!(
+        "The length of the answer must be 4, but it is 5.",
+        host.validate_guess(&[1, 2, 3, 4, 5]).unwrap_err()[0].to_string()
+    );
+    assert_eq!(
+        "The letter at index 2 of the answer is a duplicate of the letter at index 0.",
+        host.answer(&[1, 2, 1, 3]).unwrap_err().to_string()
+    );
+    assert_eq!(
+        "The length of the answer must be between 1 and 10, but it is 11.",
+        bulls_and_cows::Host::build(bulls_and_cows::Letters::generate_numeric_letters(), 11)
+            .unwrap_err()
+            .to_string()
+    );
 }
@@ -0,0 +1,49 @@
+use bulls_and_cows::{Alphabet, Host, HostError, Letters};
+
+#[test]
+fn alphabet() {
+    let alphabet: Alphabet<char> = "banana".chars().collect();
+
+    assert_eq!(3, alphabet.len());
+    assert_eq!(&['b', 'a', 'n'], alphabet.as_slice());
+    assert_eq!(Some(2), alphabet.index_of(&'n'));
+    assert_eq!(None, alphabet.index_of(&'z'));
+    assert_eq!(Some(&'a'), alphabet.get(1));
+    assert_eq!(None, alphabet.get(3));
+
+    let numeric = Letters::generate_numeric_letters();
+
+    assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], numeric.as_slice());
+}
+
+#[test]
+fn deterministic_host() {
+    let host = Host::build(Alphabet::from(['R', 'G', 'B', 'Y', 'O', 'P']), 4).unwrap();
+
+    assert_eq!(&['R', 'G', 'B', 'Y'], host.get_answer());
+}
+
+#[test]
+fn generators() {
+    assert_eq!(16, Letters::generate_hexadecimal_letters().len());
+    assert_eq!(Some(10), Letters::generate_hexadecimal_letters().index_of(&'A'));
+    assert_eq!(26, Letters::generate_uppercase_letters().len());
+    assert_eq!(26, Letters::generate_lowercase_letters().len());
+    assert_eq!(62, Letters::generate_alphanumeric_letters().len());
+
+    assert_eq!(&['a', 'b', 'c'], Letters::from_char_range('a'..='c').unwrap().as_slice());
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty_range = 'c'..='a';
+    assert_eq!(Err(HostError::LettersEmpty), Letters::from_char_range(empty_range));
+
+    assert_eq!(&['R', 'G', 'B', 'Y', 'O', 'P'], Letters::from_chars("RGBYOP").unwrap().as_slice());
+    assert_eq!(Err(HostError::LettersEmpty), Letters::from_chars(""));
+    assert_eq!(Err(HostError::LettersContainsDuplicatedLetter('R')), Letters::from_chars("RGBR"));
+
+    assert_eq!(&[3, 1, 2], Letters::from_iter(vec![3, 1, 2]).unwrap().as_slice());
+    assert_eq!(Err(HostError::LettersEmpty), Letters::from_iter(Vec::<u8>::new()));
+    assert_eq!(
+        Err(HostError::LettersContainsDuplicatedLetter(1)),
+        Letters::from_iter(vec![1, 2, 1])
+    );
+}
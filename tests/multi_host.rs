@@ -0,0 +1,168 @@
+use bulls_and_cows::{
+    play::players::{Guesser, MultiComputerGuesser},
+    Constraints, Host, HostError, Letters, MultiHost, Score,
+};
+
+#[test]
+fn known_answers() {
+    let mut host = MultiHost::build_with_known_answers(Letters::generate_numeric_letters(), vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+    ])
+    .unwrap();
+
+    assert_eq!(2, host.len());
+
+    assert_eq!(vec![Score::new(2, 2), Score::new(0, 0)], host.answer(&[1, 2, 4, 3]).unwrap());
+    assert_eq!(&[false, false], host.get_solved());
+
+    assert_eq!(vec![Score::new(0, 0), Score::new(4, 0)], host.answer(&[5, 6, 7, 8]).unwrap());
+    assert_eq!(&[false, true], host.get_solved());
+    assert!(!host.is_all_solved());
+
+    assert!(matches!(host.answer(&[1, 2, 3]), Err(HostError::AnswerLengthIncorrect { .. })));
+    assert!(matches!(
+        host.answer(&[1, 2, 3, 3]),
+        Err(HostError::AnswerContainsDuplicatedLetter { .. })
+    ));
+
+    host.answer(&[1, 2, 3, 4]).unwrap();
+    assert!(host.is_all_solved());
+
+    let mut rng = <rand_chacha::ChaCha8Rng as rand::SeedableRng>::seed_from_u64(3);
+
+    host.renew_with_random_answers_rng(3, &mut rng).unwrap();
+    assert_eq!(&[false, false], host.get_solved());
+}
+
+#[test]
+fn incompatible_hosts() {
+    assert!(matches!(MultiHost::<u8>::from_hosts(Vec::new()), Err(HostError::HostsIncompatible)));
+
+    let letters = Letters::generate_numeric_letters();
+
+    assert!(matches!(
+        MultiHost::from_hosts(vec![
+            Host::build_with_known_answer(letters.clone(), vec![1, 2, 3, 4]).unwrap(),
+            Host::build_with_known_answer(letters, vec![1, 2, 3]).unwrap(),
+        ]),
+        Err(HostError::HostsIncompatible)
+    ));
+}
+
+#[test]
+fn multi_computer_guesser() {
+    let mut host = MultiHost::build_with_known_answers(Letters::generate_numeric_letters(), vec![
+        vec![
+            0, 1, 2, 3
+        ];
+        4
+    ])
+    .unwrap();
+
+    let mut rng = <rand_chacha::ChaCha8Rng as rand::SeedableRng>::seed_from_u64(7);
+
+    host.renew_with_random_answers_rng(4, &mut rng).unwrap();
+
+    let mut guesser = MultiComputerGuesser::new(&host, 0).unwrap();
+
+    guesser.set_seed(7);
+
+    for _ in 0..40 {
+        let guess = guesser.guess().unwrap();
+
+        let replies = host.answer(&guess).unwrap();
+
+        if host.is_all_solved() {
+            break;
+        }
+
+        guesser.add_condition(&guess, replies);
+
+        assert_eq!(host.get_solved(), guesser.get_solved());
+    }
+
+    assert!(host.is_all_solved());
+}
+
+#[test]
+fn failed_renewal() {
+    let letters = Letters::generate_numeric_letters();
+
+    let mut constraints = Constraints::new();
+
+    constraints.require(0);
+    constraints.require(1);
+    constraints.require(2);
+
+    let mut host = MultiHost::from_hosts(vec![
+        Host::build_with_known_answer(letters.clone(), vec![5, 6, 7]).unwrap(),
+        Host::build_with_known_answer_constraints(letters, vec![0, 1, 2], constraints).unwrap(),
+    ])
+    .unwrap();
+
+    host.answer(&[5, 6, 7]).unwrap();
+
+    let mut rng = <rand_chacha::ChaCha8Rng as rand::SeedableRng>::seed_from_u64(5);
+
+    // the second host cannot have an answer with 2 letters, so nothing is renewed
+    assert!(matches!(
+        host.renew_with_random_answers_rng(2, &mut rng),
+        Err(HostError::ConstraintsUnsatisfiable)
+    ));
+
+    assert_eq!(&[5, 6, 7], host.get_hosts()[0].get_answer());
+    assert_eq!(&[0, 1, 2], host.get_hosts()[1].get_answer());
+    assert_eq!(&[true, false], host.get_solved());
+}
+
+#[test]
+fn hidden_length_multi_computer_guesser() {
+    let letters = Letters::generate_numeric_letters();
+
+    let mut host = MultiHost::from_hosts(vec![
+        Host::build_with_known_answer(letters.clone(), vec![3, 1]).unwrap().into_hidden_length(),
+        Host::build_with_known_answer(letters, vec![4, 0, 2]).unwrap().into_hidden_length(),
+    ])
+    .unwrap();
+
+    let mut guesser = MultiComputerGuesser::new_with_answer_lengths(&host, 1..=3, 0).unwrap();
+
+    guesser.set_seed(2);
+
+    for _ in 0..30 {
+        let guess = guesser.guess().unwrap();
+
+        let replies = host.answer(&guess).unwrap();
+
+        if host.is_all_solved() {
+            break;
+        }
+
+        guesser.add_condition(&guess, replies);
+    }
+
+    assert!(host.is_all_solved());
+}
+
+#[test]
+fn all_solved_multi_computer_guesser() {
+    let mut host = MultiHost::build_with_known_answers(Letters::generate_numeric_letters(), vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+    ])
+    .unwrap();
+
+    let mut guesser = MultiComputerGuesser::new(&host, 0).unwrap();
+
+    guesser.set_seed(7);
+
+    for guess in [[1, 2, 3, 4], [5, 6, 7, 8]] {
+        let replies = host.answer(&guess).unwrap();
+
+        guesser.add_condition(&guess, replies);
+    }
+
+    assert_eq!(&[true, true], guesser.get_solved());
+    assert!(matches!(guesser.guess(), Err(HostError::AnswersSolved)));
+}
@@ -0,0 +1,31 @@
+use bulls_and_cows::Score;
+
+#[test]
+fn display_and_parse() {
+    assert_eq!("1A2B", Score::new(1, 2).to_string());
+    assert_eq!(Score::new(1, 2), "1A2B".parse().unwrap());
+    assert_eq!(Score::new(0, 4), " 0a 4b ".parse().unwrap());
+    assert_eq!(Score::new(1, 0), "1A".parse().unwrap());
+    assert!("1B".parse::<Score>().is_err());
+}
+
+#[test]
+fn validity() {
+    assert!(Score::new(4, 0).is_win(4));
+    assert!(!Score::new(3, 0).is_win(4));
+
+    assert!(Score::new(0, 4).is_valid(4));
+    assert!(Score::new(2, 2).is_valid(4));
+    assert!(Score::new(3, 0).is_valid(4));
+    assert!(!Score::new(3, 1).is_valid(4));
+    assert!(!Score::new(2, 3).is_valid(4));
+    assert!(!Score::new(5, 0).is_valid(4));
+    assert!(!Score::new(usize::MAX, 1).is_valid(4));
+}
+
+#[test]
+fn ordering() {
+    assert!(Score::new(1, 0) > Score::new(0, 4));
+    assert!(Score::new(1, 2) > Score::new(1, 1));
+    assert!(Score::new(4, 0) > Score::new(2, 2));
+}
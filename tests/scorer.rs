@@ -0,0 +1,271 @@
+use bulls_and_cows::{
+    play::players::{ComputerGuesser, Guesser},
+    scorer::{
+        Bagels, BullsAndCows, BullsOnly, Clues, HiddenLength, HiddenLengthReply, Jotto, LengthHint,
+        Matches, PackedCode, Scorer, TotalMatches,
+    },
+    Host, HostError, Letters, Score,
+};
+
+fn solve<S: Scorer<u8> + Clone>(host: &Host<u8, S>) -> usize {
+    let mut guesser = ComputerGuesser::new(host, 0).unwrap();
+
+    guesser.set_seed(0);
+
+    for times in 1..=720 {
+        let guess = guesser.guess().unwrap();
+        let reply = host.answer(&guess).unwrap();
+
+        if host.is_win(&reply) {
+            assert_eq!(host.get_answer(), guess.as_slice());
+
+            return times;
+        }
+
+        guesser.add_condition(&guess, reply);
+    }
+
+    unreachable!()
+}
+
+#[test]
+fn bulls_and_cows() {
+    let host =
+        Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3]).unwrap();
+
+    assert_eq!(Score::new(1, 1), host.answer(&[1, 3, 4]).unwrap());
+
+    solve(&host);
+}
+
+#[test]
+fn bulls_only() {
+    let host = Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3])
+        .unwrap()
+        .with_scorer(BullsOnly);
+
+    assert_eq!(1, host.answer(&[1, 3, 4]).unwrap());
+    assert!(host.is_win(&3));
+
+    solve(&host);
+}
+
+#[test]
+fn total_matches() {
+    let host = Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3])
+        .unwrap()
+        .with_scorer(TotalMatches);
+
+    assert_eq!(
+        Matches {
+            count: 2, exact: false
+        },
+        host.answer(&[1, 3, 4]).unwrap()
+    );
+    assert!(!host.is_win(&host.answer(&[3, 2, 1]).unwrap()));
+    assert!(host.is_win(&host.answer(&[1, 2, 3]).unwrap()));
+
+    solve(&host);
+}
+
+#[test]
+fn jotto() {
+    let mut host = Host::build(Letters::generate_numeric_letters(), 3).unwrap();
+
+    host.set_duplicated_letters_allowed(true).unwrap();
+    host.renew_with_known_answer(vec![1, 1, 2]).unwrap();
+
+    let host = host.with_scorer(Jotto);
+
+    assert_eq!(1, host.answer(&[1, 1, 1]).unwrap().count);
+    assert_eq!(2, host.answer(&[2, 1, 5]).unwrap().count);
+    assert!(host.is_win(&host.answer(&[1, 1, 2]).unwrap()));
+    assert!(!host.is_win(&host.answer(&[2, 1, 1]).unwrap()));
+
+    assert_eq!(2, TotalMatches.score(&[1, 1, 2], &[1, 1, 1]).count);
+}
+
+#[test]
+fn bagels() {
+    let host = Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3])
+        .unwrap()
+        .with_scorer(Bagels);
+
+    assert_eq!(
+        Clues {
+            fermi: 1, pico: 1
+        },
+        host.answer(&[1, 3, 4]).unwrap()
+    );
+    assert_eq!("Fermi Pico", host.answer(&[1, 3, 4]).unwrap().to_string());
+    assert_eq!("Pico Pico", host.answer(&[3, 1, 4]).unwrap().to_string());
+    assert_eq!("Bagels", host.answer(&[4, 5, 6]).unwrap().to_string());
+
+    solve(&host);
+}
+
+#[test]
+fn seeded_guesser() {
+    let host =
+        Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3]).unwrap();
+
+    let mut guesser_1 = ComputerGuesser::new(&host, 0).unwrap();
+    let mut guesser_2 = ComputerGuesser::new(&host, 0).unwrap();
+
+    guesser_1.set_seed(5);
+    guesser_2.set_seed(5);
+
+    loop {
+        let guess = guesser_1.guess().unwrap();
+
+        assert_eq!(guess, guesser_2.guess().unwrap());
+
+        let reply = host.answer(&guess).unwrap();
+
+        if host.is_win(&reply) {
+            break;
+        }
+
+        guesser_1.add_condition(&guess, reply);
+        guesser_2.add_condition(&guess, reply);
+    }
+}
+
+#[test]
+fn packed() {
+    fn check<S: Scorer<u8>>(scorer: S, letters: &bulls_and_cows::Alphabet<u8>, codes: &[Vec<u8>]) {
+        for answer in codes {
+            let packed_answer = PackedCode::new(letters, answer).unwrap();
+
+            for guess in codes {
+                let packed_guess = PackedCode::new(letters, guess).unwrap();
+
+                assert_eq!(
+                    Some(scorer.score(answer, guess)),
+                    scorer.score_packed(&packed_answer, &packed_guess),
+                    "{:?} {:?}",
+                    answer,
+                    guess
+                );
+            }
+        }
+    }
+
+    let letters = Letters::from_iter(0u8..5).unwrap();
+
+    let mut codes = Vec::new();
+
+    for a in 0..5 {
+        for b in 0..5 {
+            for c in 0..5 {
+                if a != b && b != c && a != c {
+                    codes.push(vec![a, b, c]);
+                }
+            }
+        }
+    }
+
+    check(BullsAndCows, &letters, &codes);
+    check(BullsOnly, &letters, &codes);
+    check(TotalMatches, &letters, &codes);
+    check(Jotto, &letters, &codes);
+    check(Bagels, &letters, &codes);
+
+    let letters = Letters::from_iter(0u8..64).unwrap();
+
+    let codes: Vec<Vec<u8>> = (0..64u8)
+        .step_by(4)
+        .map(|offset| (0..16).map(|i| (offset + i * 5) % 64).collect())
+        .chain([(48..64).collect(), (48..64).rev().collect(), (0..16).collect()])
+        .collect();
+
+    check(BullsAndCows, &letters, &codes);
+    check(Jotto, &letters, &codes);
+
+    assert_eq!(None, PackedCode::new(&letters, &[1, 2, 1]));
+    assert_eq!(None, PackedCode::new(&letters, &[1, 2, 64]));
+    assert_eq!(None, PackedCode::new(&letters, &(0..17).collect::<Vec<u8>>()));
+    assert_eq!(None, PackedCode::new(&Letters::from_iter(0u8..65).unwrap(), &[1, 2, 3]));
+}
+
+#[test]
+fn hidden_length() {
+    let host = Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 3, 2])
+        .unwrap()
+        .into_hidden_length();
+
+    let reply = |bulls, cows, length| HiddenLengthReply {
+        reply: Score::new(bulls, cows),
+        length,
+    };
+
+    assert_eq!(reply(1, 1, LengthHint::TooShort), host.answer(&[1, 2]).unwrap());
+    assert_eq!(reply(2, 1, LengthHint::TooLong), host.answer(&[1, 3, 4, 2]).unwrap());
+    assert_eq!(reply(0, 2, LengthHint::Exact), host.answer(&[2, 1, 5]).unwrap());
+    assert_eq!("1A1B (too short)", host.answer(&[1, 2]).unwrap().to_string());
+
+    assert!(!host.is_win(&host.answer(&[1, 3]).unwrap()));
+    assert!(host.is_win(&host.answer(&[1, 3, 2]).unwrap()));
+
+    assert!(matches!(host.answer(&[]), Err(HostError::AnswerLengthIncorrect { .. })));
+    assert!(matches!(
+        host.answer(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0]),
+        Err(HostError::AnswerLengthIncorrect { .. })
+    ));
+
+    // a guess which starts with the answer is not the answer
+    let total_matches_host =
+        Host::build_with_known_answer(Letters::generate_numeric_letters(), vec![1, 2, 3])
+            .unwrap()
+            .with_scorer(HiddenLength::new(TotalMatches));
+
+    let too_long = total_matches_host.answer(&[1, 2, 3, 4]).unwrap();
+
+    assert_eq!(
+        HiddenLengthReply {
+            reply:  Matches {
+                count: 3, exact: false
+            },
+            length: LengthHint::TooLong,
+        },
+        too_long
+    );
+    assert!(!total_matches_host.is_win(&too_long));
+    assert!(total_matches_host.is_win(&total_matches_host.answer(&[1, 2, 3]).unwrap()));
+
+    for length in 1..=5 {
+        let mut guesser = ComputerGuesser::new_with_answer_lengths(&host, 1..=5, 0).unwrap();
+
+        guesser.set_seed(length as u64);
+
+        let host = Host::build_with_known_answer(
+            Letters::generate_numeric_letters(),
+            (0..length).collect(),
+        )
+        .unwrap()
+        .with_scorer(HiddenLength::new(BullsAndCows));
+
+        let mut solved = false;
+
+        for _ in 0..20 {
+            let guess = guesser.guess().unwrap();
+
+            let reply = host.answer(&guess).unwrap();
+
+            if host.is_win(&reply) {
+                solved = true;
+
+                break;
+            }
+
+            guesser.add_condition(&guess, reply);
+        }
+
+        assert!(solved);
+    }
+
+    assert!(matches!(
+        ComputerGuesser::new_with_answer_lengths(&host, 11..=12, 0),
+        Err(HostError::AnswerLengthIncorrect { .. })
+    ));
+}
@@ -0,0 +1,94 @@
+use bulls_and_cows::{
+    source::{Uniform, WeightedList, WithoutReplacement},
+    Host, HostError, Letters,
+};
+
+#[test]
+fn uniform() {
+    let mut host = Host::build(Letters::generate_numeric_letters(), 4).unwrap();
+
+    host.set_seed(1);
+
+    let mut source = Uniform::new(3);
+
+    for _ in 0..100 {
+        host.renew_with_source(&mut source).unwrap();
+
+        assert_eq!(3, host.get_answer_length());
+    }
+}
+
+#[test]
+fn weighted_list() {
+    const TIMES: usize = 4000;
+
+    let mut host = Host::build(Letters::generate_numeric_letters(), 4).unwrap();
+
+    host.set_seed(2);
+
+    let mut source: WeightedList<u8> = [
+        (vec![1, 2, 3, 4], 3),
+        (vec![5, 6, 7, 8], 1),
+        (vec![0, 0, 1, 2], 100), // not accepted because of the duplicated letter
+        (vec![9, 8, 7, 6], 0),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut count = 0;
+
+    for _ in 0..TIMES {
+        host.renew_with_source(&mut source).unwrap();
+
+        match host.get_answer() {
+            [1, 2, 3, 4] => count += 1,
+            [5, 6, 7, 8] => (),
+            answer => panic!("{:?}", answer),
+        }
+    }
+
+    // the first answer is expected to be drawn in 3/4 of the rounds
+    let expected = TIMES * 3 / 4;
+
+    assert!(count > expected * 9 / 10 && count < expected * 11 / 10, "{}", count);
+
+    assert!(matches!(
+        host.renew_with_source(&mut WeightedList::new()),
+        Err(HostError::SourceEmpty)
+    ));
+}
+
+#[test]
+fn without_replacement() {
+    let mut host = Host::build(Letters::generate_numeric_letters(), 4).unwrap();
+
+    host.set_seed(3);
+
+    let mut source = WithoutReplacement::new(vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![1, 1, 2, 2], // not accepted because of the duplicated letters
+        vec![0, 1, 2, 3],
+    ]);
+
+    for _ in 0..5 {
+        let mut drawn = Vec::new();
+
+        for _ in 0..3 {
+            host.renew_with_source(&mut source).unwrap();
+
+            assert!(!drawn.contains(&host.get_answer().to_vec()));
+
+            drawn.push(host.get_answer().to_vec());
+        }
+
+        drawn.sort();
+
+        assert_eq!(vec![vec![0, 1, 2, 3], vec![1, 2, 3, 4], vec![5, 6, 7, 8]], drawn);
+    }
+
+    assert!(matches!(
+        host.renew_with_source(&mut WithoutReplacement::new(vec![vec![1, 1, 2, 2]])),
+        Err(HostError::SourceEmpty)
+    ));
+}